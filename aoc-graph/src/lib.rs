@@ -0,0 +1,322 @@
+//! Small weighted graphs with named nodes, and the Hamiltonian path and
+//! cycle searches the routing days are built on.
+
+use std::collections::HashMap;
+use std::ops::Add;
+
+pub type Node = usize;
+
+/// What edge weights need to support to be searched.
+pub trait Weight: Copy + Ord + Add<Output = Self> + Default {}
+
+impl<W: Copy + Ord + Add<Output = W> + Default> Weight for W {}
+
+/// A directed graph with weighted edges, stored as adjacency matrix.
+///
+/// Nodes are interned by name and numbered from 0 in order of appearance.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Graph<W> {
+    names: Vec<String>,
+    nodes: HashMap<String, Node>,
+    weights: Vec<Vec<Option<W>>>,
+}
+
+impl<W> Default for Graph<W> {
+    fn default() -> Self {
+        Graph { names: Vec::new(), nodes: HashMap::new(), weights: Vec::new() }
+    }
+}
+
+impl<W: Weight> Graph<W> {
+    pub fn new() -> Graph<W> {
+        Graph::default()
+    }
+
+    /// The node called `name`, added if it does not exist yet.
+    pub fn intern(&mut self, name: &str) -> Node {
+        if let Some(node) = self.nodes.get(name) {
+            return *node;
+        }
+        let node = self.names.len();
+        self.names.push(name.to_string());
+        self.nodes.insert(name.to_string(), node);
+        self.weights.iter_mut().for_each(|row| row.push(None));
+        self.weights.push(vec![None; node + 1]);
+        node
+    }
+
+    pub fn node(&self, name: &str) -> Option<Node> {
+        self.nodes.get(name).copied()
+    }
+
+    pub fn name(&self, node: Node) -> &str {
+        &self.names[node]
+    }
+
+    /// Number of nodes.
+    pub fn len(&self) -> usize {
+        self.names.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.names.is_empty()
+    }
+
+    /// Set the weight of the edge from `from` to `to`.
+    pub fn set_weight(&mut self, from: Node, to: Node, weight: W) {
+        self.weights[from][to] = Some(weight);
+    }
+
+    /// Add `weight` to the edge from `from` to `to`, creating it if missing.
+    pub fn add_weight(&mut self, from: Node, to: Node, weight: W) {
+        let edge = &mut self.weights[from][to];
+        *edge = Some(edge.map_or(weight, |current| current + weight));
+    }
+
+    /// Connect `a` and `b` in both directions.
+    pub fn connect(&mut self, a: Node, b: Node, weight: W) {
+        self.set_weight(a, b, weight);
+        self.set_weight(b, a, weight);
+    }
+
+    pub fn weight(&self, from: Node, to: Node) -> Option<W> {
+        self.weights[from][to]
+    }
+
+    /// The nodes reachable from `node` with the weights of the edges.
+    pub fn neighbors(&self, node: Node) -> impl Iterator<Item = (Node, W)> + '_ {
+        self.weights[node].iter().enumerate().filter_map(|(to, weight)| weight.map(|weight| (to, weight)))
+    }
+
+    /// Names of the nodes along `route`.
+    pub fn names<'a>(&'a self, route: &Route<W>) -> Vec<&'a str> {
+        route.nodes.iter().map(|node| self.name(*node)).collect()
+    }
+}
+
+/// Whether to look for the cheapest or the most expensive route.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Goal {
+    Min,
+    Max,
+}
+
+impl Goal {
+    fn improves<W: Ord>(&self, candidate: W, best: Option<W>) -> bool {
+        match (self, best) {
+            (_, None) => true,
+            (Goal::Min, Some(best)) => candidate < best,
+            (Goal::Max, Some(best)) => candidate > best,
+        }
+    }
+}
+
+/// Whether the route may start and end anywhere, or has to return to its start.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Shape {
+    Path,
+    Cycle,
+}
+
+/// A route visiting every node once. The return to the start of a cycle is
+/// included in the cost, but its start is not repeated in the nodes.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Route<W> {
+    pub nodes: Vec<Node>,
+    pub cost: W,
+}
+
+fn close<W: Weight>(graph: &Graph<W>, shape: Shape, nodes: &[Node], cost: W) -> Option<W> {
+    match (shape, nodes) {
+        (Shape::Cycle, [first, .., last]) => Some(cost + graph.weight(*last, *first)?),
+        _ => Some(cost),
+    }
+}
+
+fn extend<W: Weight>(graph: &Graph<W>, shape: Shape, goal: Goal, route: &mut Vec<Node>,
+                     visited: &mut [bool], cost: W, best: &mut Option<Route<W>>) {
+    if route.len() == graph.len() {
+        if let Some(cost) = close(graph, shape, route, cost) {
+            if goal.improves(cost, best.as_ref().map(|best| best.cost)) {
+                *best = Some(Route { nodes: route.clone(), cost });
+            }
+        }
+        return;
+    }
+    let last = *route.last().unwrap();
+    for (next, weight) in graph.neighbors(last) {
+        if !visited[next] {
+            visited[next] = true;
+            route.push(next);
+            extend(graph, shape, goal, route, visited, cost + weight, best);
+            route.pop();
+            visited[next] = false;
+        }
+    }
+}
+
+/// Best route visiting every node once, trying all permutations.
+///
+/// Returns `None` if there is no such route.
+pub fn brute_force<W: Weight>(graph: &Graph<W>, shape: Shape, goal: Goal) -> Option<Route<W>> {
+    if graph.is_empty() {
+        return Some(Route { nodes: Vec::new(), cost: W::default() });
+    }
+    // a cycle can start anywhere
+    let starts = if shape == Shape::Cycle { 1 } else { graph.len() };
+    let mut best = None;
+    for start in 0..starts {
+        let mut visited = vec![false; graph.len()];
+        visited[start] = true;
+        extend(graph, shape, goal, &mut vec![start], &mut visited, W::default(), &mut best);
+    }
+    best
+}
+
+/// Most nodes `held_karp` builds its tables for; they already take several
+/// megabytes per weight byte at this size.
+pub const HELD_KARP_LIMIT: usize = 16;
+
+/// Best route visiting every node once with the Held-Karp algorithm, in
+/// O(2^n n^2) time and O(2^n n) memory.
+///
+/// Returns `None` if there is no such route, or if the graph has more than
+/// `HELD_KARP_LIMIT` nodes; callers have to cap their graphs at that size.
+pub fn held_karp<W: Weight>(graph: &Graph<W>, shape: Shape, goal: Goal) -> Option<Route<W>> {
+    let n = graph.len();
+    if n == 0 {
+        return Some(Route { nodes: Vec::new(), cost: W::default() });
+    }
+    if n > HELD_KARP_LIMIT {
+        return None;
+    }
+    let full = (1usize << n) - 1;
+    // best cost of a route through the nodes of a set, ending at a node,
+    // and the node before that
+    let mut best: Vec<Vec<Option<W>>> = vec![vec![None; n]; 1 << n];
+    let mut previous = vec![vec![None; n]; 1 << n];
+    let starts = if shape == Shape::Cycle { 1 } else { n };
+    for start in 0..starts {
+        best[1 << start][start] = Some(W::default());
+    }
+    for set in 1..=full {
+        for last in 0..n {
+            let Some(cost) = best[set][last] else {
+                continue;
+            };
+            for (next, weight) in graph.neighbors(last).filter(|(next, _)| set & (1 << next) == 0) {
+                let extended = set | (1 << next);
+                if goal.improves(cost + weight, best[extended][next]) {
+                    best[extended][next] = Some(cost + weight);
+                    previous[extended][next] = Some(last);
+                }
+            }
+        }
+    }
+
+    let mut end: Option<(W, Node)> = None;
+    for (last, cost) in best[full].iter().enumerate() {
+        let Some(cost) = *cost else {
+            continue;
+        };
+        // cycles start at node 0
+        let ends = [0, last];
+        let ends = if n == 1 { &ends[1..] } else { &ends[..] };
+        if let Some(cost) = close(graph, shape, ends, cost) {
+            if goal.improves(cost, end.map(|(cost, _)| cost)) {
+                end = Some((cost, last));
+            }
+        }
+    }
+    let (cost, mut last) = end?;
+    let mut nodes = vec![last];
+    let mut set = full;
+    while let Some(before) = previous[set][last] {
+        set &= !(1 << last);
+        last = before;
+        nodes.push(last);
+    }
+    nodes.reverse();
+    Some(Route { nodes, cost })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn cities() -> Graph<usize> {
+        let mut graph = Graph::new();
+        let london = graph.intern("London");
+        let dublin = graph.intern("Dublin");
+        let belfast = graph.intern("Belfast");
+        graph.connect(london, dublin, 464);
+        graph.connect(london, belfast, 518);
+        graph.connect(dublin, belfast, 141);
+        graph
+    }
+
+    #[test]
+    fn test_intern() {
+        let mut graph = cities();
+        assert_eq!(1, graph.intern("Dublin"));
+        assert_eq!(Some(2), graph.node("Belfast"));
+        assert_eq!(None, graph.node("Paris"));
+        graph.add_weight(0, 1, 10);
+        assert_eq!(Some(474), graph.weight(0, 1));
+        assert_eq!(vec![(1, 474), (2, 518)], graph.neighbors(0).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_path() {
+        let graph = cities();
+        for solve in [brute_force, held_karp] {
+            let shortest = solve(&graph, Shape::Path, Goal::Min).unwrap();
+            assert_eq!(605, shortest.cost);
+            let names = graph.names(&shortest);
+            assert!(names == ["London", "Dublin", "Belfast"] || names == ["Belfast", "Dublin", "London"]);
+            assert_eq!(982, solve(&graph, Shape::Path, Goal::Max).unwrap().cost);
+        }
+    }
+
+    #[test]
+    fn test_cycle() {
+        let mut graph = cities();
+        let paris = graph.intern("Paris");
+        graph.connect(0, paris, 344);
+        for solve in [brute_force, held_karp] {
+            // Paris is a dead end
+            assert_eq!(None, solve(&graph, Shape::Cycle, Goal::Max));
+        }
+        graph.connect(1, paris, 778);
+        for solve in [brute_force, held_karp] {
+            // Belfast can only be reached from London and Dublin
+            let route = solve(&graph, Shape::Cycle, Goal::Min).unwrap();
+            assert_eq!(344 + 518 + 141 + 778, route.cost);
+            assert_eq!(4, route.nodes.len());
+            assert_eq!(0, route.nodes[0]);
+        }
+    }
+
+    #[test]
+    fn test_small() {
+        let mut graph: Graph<i64> = Graph::new();
+        assert_eq!(Some(Route { nodes: Vec::new(), cost: 0 }), held_karp(&graph, Shape::Cycle, Goal::Max));
+        graph.intern("Alice");
+        assert_eq!(Some(Route { nodes: vec![0], cost: 0 }), held_karp(&graph, Shape::Cycle, Goal::Max));
+        assert_eq!(Some(Route { nodes: vec![0], cost: 0 }), brute_force(&graph, Shape::Cycle, Goal::Max));
+    }
+
+    #[test]
+    fn test_above_limit() {
+        // a chain is quick to brute force, but too large for the tables
+        let mut graph: Graph<i64> = Graph::new();
+        for node in 0..=HELD_KARP_LIMIT {
+            graph.intern(&node.to_string());
+        }
+        for node in 0..HELD_KARP_LIMIT {
+            graph.connect(node, node + 1, 1);
+        }
+        assert_eq!(None, held_karp(&graph, Shape::Path, Goal::Min));
+        assert_eq!(HELD_KARP_LIMIT as i64, brute_force(&graph, Shape::Path, Goal::Min).unwrap().cost);
+    }
+}
@@ -0,0 +1,261 @@
+//! A fixed size two-dimensional grid, shared by the days working on maps.
+//!
+//! Cells are addressed as `(x, y)`, x being the column and y the row, both
+//! starting at 0 in the top left corner.
+
+use std::ops::{Bound, Index, IndexMut, Range, RangeBounds};
+
+use aoc_parse::{lines, ParseError};
+
+const OFFSETS4: [(isize, isize); 4] = [(0, -1), (1, 0), (0, 1), (-1, 0)];
+const OFFSETS8: [(isize, isize); 8] = [(-1, -1), (0, -1), (1, -1), (1, 0), (1, 1), (0, 1), (-1, 1), (-1, 0)];
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid2D<T> {
+    data: Vec<T>,
+    width: usize,
+    height: usize,
+}
+
+impl<T: Clone> Grid2D<T> {
+    /// A `width` x `height` grid with every cell set to `value`.
+    pub fn new(width: usize, height: usize, value: T) -> Grid2D<T> {
+        Grid2D { data: vec![value; width * height], width, height }
+    }
+}
+
+impl<T> Grid2D<T> {
+    /// A grid of the given `width` from its cells in row-major order, `None`
+    /// if they do not fill whole rows.
+    pub fn from_vec(width: usize, data: Vec<T>) -> Option<Grid2D<T>> {
+        match width {
+            0 if data.is_empty() => Some(Grid2D { data, width, height: 0 }),
+            0 => None,
+            _ if data.len().is_multiple_of(width) => Some(Grid2D { height: data.len() / width, data, width }),
+            _ => None,
+        }
+    }
+
+    /// Parse a map with one character per cell, `cell` decides what a
+    /// character means, or rejects it. All lines must have the same length.
+    pub fn parse(input: &str, cell: impl Fn(char) -> Option<T>, expected: &str) -> Result<Grid2D<T>, ParseError> {
+        let mut data = Vec::new();
+        let mut width = None;
+        for line in lines(input) {
+            let mut length = 0;
+            for (index, c) in line.text.char_indices() {
+                if width == Some(length) {
+                    return Err(line.error(&line.text[index..], "end of line"));
+                }
+                data.push(cell(c).ok_or_else(|| line.error(&line.text[index..index + c.len_utf8()], expected))?);
+                length += 1;
+            }
+            if width.is_some_and(|width| length < width) {
+                return Err(line.error_at_end(expected));
+            }
+            width = Some(length);
+        }
+        let width = width.unwrap_or(0);
+        let height = data.len().checked_div(width).unwrap_or(0);
+        Ok(Grid2D { data, width, height })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Whether `(x, y)` lies within the grid.
+    pub fn contains(&self, x: isize, y: isize) -> bool {
+        x >= 0 && y >= 0 && (x as usize) < self.width && (y as usize) < self.height
+    }
+
+    pub fn get(&self, x: isize, y: isize) -> Option<&T> {
+        self.contains(x, y).then(|| &self.data[y as usize * self.width + x as usize])
+    }
+
+    pub fn get_mut(&mut self, x: isize, y: isize) -> Option<&mut T> {
+        if self.contains(x, y) {
+            Some(&mut self.data[y as usize * self.width + x as usize])
+        } else {
+            None
+        }
+    }
+
+    /// The cell at `(x, y)`, with coordinates wrapping around at the edges.
+    /// Panics on an empty grid.
+    pub fn get_wrapping(&self, x: isize, y: isize) -> &T {
+        let x = x.rem_euclid(self.width as isize) as usize;
+        let y = y.rem_euclid(self.height as isize) as usize;
+        &self[(x, y)]
+    }
+
+    /// Positions of the up to 4 horizontal and vertical neighbors of `(x, y)`.
+    pub fn neighbors4(&self, x: usize, y: usize) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.neighbors(x, y, &OFFSETS4)
+    }
+
+    /// Positions of the up to 8 neighbors of `(x, y)`, diagonals included.
+    pub fn neighbors8(&self, x: usize, y: usize) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.neighbors(x, y, &OFFSETS8)
+    }
+
+    fn neighbors<'a>(&'a self, x: usize, y: usize, offsets: &'static [(isize, isize)])
+                     -> impl Iterator<Item = (usize, usize)> + 'a {
+        offsets.iter()
+               .map(move |(dx, dy)| (x as isize + dx, y as isize + dy))
+               .filter(|(x, y)| self.contains(*x, *y))
+               .map(|(x, y)| (x as usize, y as usize))
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.data[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn row_mut(&mut self, y: usize) -> &mut [T] {
+        &mut self.data[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        // chunks panics on a zero width
+        self.data.chunks(self.width.max(1))
+    }
+
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        assert!(x < self.width, "column {} out of bounds", x);
+        self.data.iter().skip(x).step_by(self.width)
+    }
+
+    /// The rows of the rectangle spanned by the ranges `xs` and `ys`.
+    /// Panics if it does not lie within the grid.
+    pub fn region(&self, xs: impl RangeBounds<usize>, ys: impl RangeBounds<usize>) -> impl Iterator<Item = &[T]> {
+        let xs = resolve(xs, self.width);
+        let ys = resolve(ys, self.height);
+        ys.map(move |y| &self.row(y)[xs.clone()])
+    }
+
+    /// Mutable rows of the rectangle spanned by the ranges `xs` and `ys`.
+    /// Panics if it does not lie within the grid.
+    pub fn region_mut(&mut self, xs: impl RangeBounds<usize>, ys: impl RangeBounds<usize>) -> impl Iterator<Item = &mut [T]> {
+        let xs = resolve(xs, self.width);
+        let ys = resolve(ys, self.height);
+        self.data.chunks_mut(self.width.max(1))
+                 .skip(ys.start)
+                 .take(ys.len())
+                 .map(move |row| &mut row[xs.clone()])
+    }
+
+    /// All cells in row-major order.
+    pub fn iter(&self) -> std::slice::Iter<'_, T> {
+        self.data.iter()
+    }
+
+    pub fn iter_mut(&mut self) -> std::slice::IterMut<'_, T> {
+        self.data.iter_mut()
+    }
+
+    /// All positions in row-major order.
+    pub fn positions(&self) -> impl Iterator<Item = (usize, usize)> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| (x, y)))
+    }
+
+    pub fn map<U>(&self, f: impl Fn(&T) -> U) -> Grid2D<U> {
+        Grid2D { data: self.data.iter().map(f).collect(), width: self.width, height: self.height }
+    }
+
+    /// Render the grid as text, one line per row.
+    pub fn render(&self, cell: impl Fn(&T) -> char) -> String {
+        let lines: Vec<String> = self.rows().map(|row| row.iter().map(&cell).collect()).collect();
+        lines.join("\n")
+    }
+}
+
+fn resolve(range: impl RangeBounds<usize>, len: usize) -> Range<usize> {
+    let start = match range.start_bound() {
+        Bound::Included(start) => *start,
+        Bound::Excluded(start) => start + 1,
+        Bound::Unbounded => 0,
+    };
+    let end = match range.end_bound() {
+        Bound::Included(end) => end + 1,
+        Bound::Excluded(end) => *end,
+        Bound::Unbounded => len,
+    };
+    assert!(start <= end && end <= len, "range {}..{} out of bounds for length {}", start, end, len);
+    start..end
+}
+
+impl<T> Index<(usize, usize)> for Grid2D<T> {
+    type Output = T;
+    fn index(&self, (x, y): (usize, usize)) -> &T {
+        assert!(x < self.width, "column {} out of bounds", x);
+        &self.data[y * self.width + x]
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid2D<T> {
+    fn index_mut(&mut self, (x, y): (usize, usize)) -> &mut T {
+        assert!(x < self.width, "column {} out of bounds", x);
+        &mut self.data[y * self.width + x]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn lights(input: &str) -> Result<Grid2D<bool>, ParseError> {
+        Grid2D::parse(input, |c| match c {
+            '#' => Some(true),
+            '.' => Some(false),
+            _ => None,
+        }, "'#' or '.'")
+    }
+
+    #[test]
+    fn test_parse_render() {
+        let grid = lights("#..\n.#.").unwrap();
+        assert_eq!((3, 2), (grid.width(), grid.height()));
+        assert!(grid[(1, 1)]);
+        assert_eq!("#..\n.#.", grid.render(|on| if *on { '#' } else { '.' }));
+        assert_eq!(Err(ParseError::new(2, 2, "'#' or '.'", "x")), lights("#..\n.x."));
+        assert_eq!(Err(ParseError::new(2, 4, "end of line", ".")), lights("#..\n.#.."));
+        assert_eq!(Err(ParseError::new(2, 3, "'#' or '.'", "")), lights("#..\n.#"));
+        assert_eq!(0, lights("").unwrap().width());
+    }
+
+    #[test]
+    fn test_access() {
+        let mut grid = Grid2D::from_vec(3, (0..6).collect()).unwrap();
+        assert_eq!(Some(&4), grid.get(1, 1));
+        assert_eq!(None, grid.get(3, 0));
+        assert_eq!(None, grid.get(0, -1));
+        assert_eq!(&5, grid.get_wrapping(-1, -1));
+        assert_eq!(&0, grid.get_wrapping(3, 2));
+        *grid.get_mut(0, 1).unwrap() = 9;
+        assert_eq!(&[9, 4, 5], grid.row(1));
+        assert_eq!(vec![&1, &4], grid.column(1).collect::<Vec<_>>());
+        assert!(Grid2D::from_vec(4, vec![0; 6]).is_none());
+    }
+
+    #[test]
+    fn test_neighbors() {
+        let grid = Grid2D::new(3, 3, 0);
+        assert_eq!(vec![(1, 0), (0, 1)], grid.neighbors4(0, 0).collect::<Vec<_>>());
+        assert_eq!(3, grid.neighbors8(2, 2).count());
+        assert_eq!(8, grid.neighbors8(1, 1).count());
+    }
+
+    #[test]
+    fn test_region() {
+        let mut grid = Grid2D::new(4, 3, 0);
+        grid.region_mut(1..=2, 1..).for_each(|row| row.fill(1));
+        assert_eq!(vec![&[1, 1][..], &[1, 1][..]], grid.region(1..3, 1..3).collect::<Vec<_>>());
+        assert_eq!(4, grid.iter().sum::<i32>());
+        assert_eq!("0000\n0110\n0110", grid.render(|cell| char::from_digit(*cell as u32, 10).unwrap()));
+    }
+}
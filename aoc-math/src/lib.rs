@@ -0,0 +1,265 @@
+//! Number theory for the arithmetic days: modular arithmetic with
+//! exponentiation, inverses and discrete logarithms, and sieves, primality
+//! and factorization for the days counting divisors.
+
+use std::collections::HashMap;
+
+/// `a * b % modulus` without overflowing.
+pub fn mod_mul(a: u64, b: u64, modulus: u64) -> u64 {
+    (a as u128 * b as u128 % modulus as u128) as u64
+}
+
+/// `base` to the power of `exponent`, modulo `modulus`, by repeated squaring.
+pub fn mod_pow(base: u64, exponent: u64, modulus: u64) -> u64 {
+    let mut result = 1 % modulus;
+    let mut base = base % modulus;
+    let mut exponent = exponent;
+    while exponent > 0 {
+        if exponent & 1 == 1 {
+            result = mod_mul(result, base, modulus);
+        }
+        base = mod_mul(base, base, modulus);
+        exponent >>= 1;
+    }
+    result
+}
+
+pub fn gcd(a: u64, b: u64) -> u64 {
+    if b == 0 {
+        a
+    } else {
+        gcd(b, a % b)
+    }
+}
+
+/// `(g, x, y)` with `g = gcd(a, b) = a * x + b * y`.
+pub fn extended_gcd(a: i128, b: i128) -> (i128, i128, i128) {
+    if b == 0 {
+        (a, 1, 0)
+    } else {
+        let (g, x, y) = extended_gcd(b, a % b);
+        (g, y, x - a / b * y)
+    }
+}
+
+/// The `x` with `a * x % modulus == 1`, if `a` and `modulus` are coprime.
+pub fn mod_inverse(a: u64, modulus: u64) -> Option<u64> {
+    let (g, x, _) = extended_gcd(a as i128, modulus as i128);
+    (g == 1).then(|| x.rem_euclid(modulus as i128) as u64)
+}
+
+/// Smallest `x` with `base^x % modulus == target % modulus`, found with
+/// baby-step giant-step in about `sqrt(modulus)` steps.
+///
+/// `base` has to be coprime to `modulus`, otherwise there is no answer.
+pub fn discrete_log(base: u64, target: u64, modulus: u64) -> Option<u64> {
+    let target = target % modulus;
+    if modulus == 1 || target == 1 {
+        return Some(0);
+    }
+    let steps = (modulus as f64).sqrt().ceil() as u64;
+    // baby steps: base^j for j < steps, keeping the smallest j
+    let mut baby = HashMap::new();
+    let mut power = 1;
+    for j in 0..steps {
+        baby.entry(power).or_insert(j);
+        power = mod_mul(power, base, modulus);
+    }
+    // giant steps: target * base^(-steps * i)
+    let giant = mod_inverse(mod_pow(base, steps, modulus), modulus)?;
+    let mut gamma = target;
+    for i in 0..steps {
+        if let Some(j) = baby.get(&gamma) {
+            return Some(i * steps + j);
+        }
+        gamma = mod_mul(gamma, giant, modulus);
+    }
+    None
+}
+
+/// All primes up to and including `limit`, by the sieve of Eratosthenes.
+pub fn primes(limit: usize) -> Vec<usize> {
+    let mut composite = vec![false; limit + 1];
+    let mut primes = Vec::new();
+    for n in 2..=limit {
+        if composite[n] {
+            continue;
+        }
+        primes.push(n);
+        for multiple in (n * n..=limit).step_by(n) {
+            composite[multiple] = true;
+        }
+    }
+    primes
+}
+
+/// Sum of the divisors of every number up to `limit`, `sums[0]` is 0.
+pub fn divisor_sums(limit: usize) -> Vec<usize> {
+    bounded_divisor_sums(limit, usize::MAX)
+}
+
+/// Like [`divisor_sums`], but every divisor `d` only counts for its first
+/// `multiples` multiples, i.e. for the numbers `n` with `n / d <= multiples`.
+pub fn bounded_divisor_sums(limit: usize, multiples: usize) -> Vec<usize> {
+    let mut sums = vec![0; limit + 1];
+    for divisor in 1..=limit {
+        for n in (divisor..=limit).step_by(divisor).take(multiples) {
+            sums[n] += divisor;
+        }
+    }
+    sums
+}
+
+/// Whether `n` is prime, by a Miller-Rabin test with bases that make it
+/// exact for every `u64`.
+pub fn is_prime(n: u64) -> bool {
+    const BASES: [u64; 12] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37];
+    if let Some(base) = BASES.iter().find(|base| n.is_multiple_of(**base)) {
+        return n == *base;
+    }
+    if n < 2 {
+        return false;
+    }
+    let zeros = (n - 1).trailing_zeros();
+    let odd = (n - 1) >> zeros;
+    BASES.iter().all(|base| {
+        let mut x = mod_pow(*base, odd, n);
+        if x == 1 || x == n - 1 {
+            return true;
+        }
+        for _ in 1..zeros {
+            x = mod_mul(x, x, n);
+            if x == n - 1 {
+                return true;
+            }
+        }
+        false
+    })
+}
+
+/// Prime factors of `n` with their exponents, smallest first. 0 and 1
+/// have none.
+pub fn factorize(n: u64) -> Vec<(u64, u32)> {
+    let mut factors = Vec::new();
+    let mut rest = n;
+    let mut candidate = 2;
+    // a prime rest is the last factor, trial division would go up to its root
+    while rest > 1 && !is_prime(rest) && candidate <= rest / candidate {
+        let mut exponent = 0;
+        while rest.is_multiple_of(candidate) {
+            rest /= candidate;
+            exponent += 1;
+        }
+        if exponent > 0 {
+            factors.push((candidate, exponent));
+        }
+        candidate += if candidate == 2 { 1 } else { 2 };
+    }
+    if rest > 1 {
+        factors.push((rest, 1));
+    }
+    factors
+}
+
+/// All divisors of `n` in ascending order, none for 0.
+pub fn divisors(n: u64) -> Vec<u64> {
+    if n == 0 {
+        return Vec::new();
+    }
+    let mut divisors = vec![1];
+    for (prime, exponent) in factorize(n) {
+        let smaller = divisors.len();
+        let mut power = 1;
+        for _ in 0..exponent {
+            power *= prime;
+            for index in 0..smaller {
+                divisors.push(divisors[index] * power);
+            }
+        }
+    }
+    divisors.sort_unstable();
+    divisors
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    #[test]
+    fn test_modular() {
+        assert_eq!(24, mod_pow(2, 10, 1000));
+        assert_eq!(0, mod_pow(5, 0, 1));
+        assert_eq!(Some(4), mod_inverse(3, 11));
+        assert_eq!(None, mod_inverse(4, 12));
+        assert_eq!(6, gcd(12, 18));
+        // the code generator of day 25
+        assert_eq!(31916031, mod_mul(20151125, 252533, 33554393));
+        assert_eq!(Some(1), discrete_log(252533, 252533, 33554393));
+        assert_eq!(Some(3), discrete_log(2, 8, 11));
+        assert_eq!(None, discrete_log(2, 3, 7));
+    }
+
+    #[test]
+    fn test_sieves() {
+        assert_eq!(vec![2, 3, 5, 7, 11, 13, 17, 19], primes(20));
+        assert_eq!(Vec::<usize>::new(), primes(1));
+        assert_eq!(vec![0, 1, 3, 4, 7, 6, 12, 8, 15, 13], divisor_sums(9));
+        assert_eq!(vec![0, 1, 3, 3, 6, 5, 9], bounded_divisor_sums(6, 2));
+    }
+
+    #[test]
+    fn test_factorize() {
+        assert_eq!(vec![(2, 3), (3, 2), (5, 1)], factorize(360));
+        assert_eq!(vec![(33554393, 1)], factorize(33554393));
+        assert_eq!(Vec::<(u64, u32)>::new(), factorize(1));
+        // the largest primes below 2^64 and 2^63, far beyond trial division
+        assert_eq!(vec![(18446744073709551557, 1)], factorize(18446744073709551557));
+        assert_eq!(vec![(2, 1), (9223372036854775783, 1)], factorize(18446744073709551566));
+        assert_eq!(vec![(3, 2), (5, 1), (7, 1)], factorize(315));
+        assert_eq!(vec![1, 2, 3, 4, 6, 12], divisors(12));
+        assert_eq!(vec![1], divisors(1));
+    }
+
+    #[test]
+    fn test_is_prime() {
+        let sieved = primes(10_000);
+        assert_eq!(sieved, (0..=10_000).filter(|n| is_prime(*n as u64)).collect::<Vec<_>>());
+        // a strong pseudoprime to the bases 2, 3, 5 and 7
+        assert!(!is_prime(3215031751));
+        assert!(is_prime(18446744073709551557));
+        assert!(!is_prime(u64::MAX));
+    }
+
+    proptest! {
+        #[test]
+        fn mod_pow_matches_multiplication(base in 0u64..1000, exponent in 0u64..50, modulus in 1u64..1000) {
+            let expected = (0..exponent).fold(1 % modulus, |power, _| power * base % modulus);
+            prop_assert_eq!(expected, mod_pow(base, exponent, modulus));
+        }
+
+        #[test]
+        fn discrete_log_inverts_mod_pow(exponent in 0u64..10_000) {
+            // 10007 is prime, so 5 is coprime to it
+            let log = discrete_log(5, mod_pow(5, exponent, 10007), 10007).unwrap();
+            prop_assert_eq!(mod_pow(5, exponent, 10007), mod_pow(5, log, 10007));
+            prop_assert!(log <= exponent);
+        }
+
+        #[test]
+        fn inverse_multiplies_to_one(a in 1u64..10_000, modulus in 2u64..10_000) {
+            match mod_inverse(a, modulus) {
+                Some(inverse) => prop_assert_eq!(1, mod_mul(a, inverse, modulus)),
+                None => prop_assert!(gcd(a, modulus) > 1),
+            }
+        }
+
+        #[test]
+        fn divisors_match_trial_division(n in 1u64..5_000) {
+            let expected: Vec<u64> = (1..=n).filter(|d| n.is_multiple_of(*d)).collect();
+            prop_assert_eq!(n, factorize(n).iter().map(|(prime, exponent)| prime.pow(*exponent)).product::<u64>());
+            prop_assert_eq!(expected.iter().sum::<u64>() as usize, divisor_sums(n as usize)[n as usize]);
+            prop_assert_eq!(expected, divisors(n));
+        }
+    }
+}
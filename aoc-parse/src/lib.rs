@@ -0,0 +1,333 @@
+//! Declarative parsing of the line-oriented puzzle formats.
+//!
+//! A [`Template`] like `"{name} to {name} = {int}"` matches a whole line and
+//! returns the text of its fields as a [`Record`]. The free functions extract
+//! numbers and lists. All errors point at the offending token of the
+//! [`Line`], like the hand-written parsers do.
+//!
+//! [`ParseError`] and [`Line`] live here as well, so crates that only parse
+//! need nothing else; `aoc-runner` re-exports them for the days.
+
+mod line;
+
+use std::str::FromStr;
+
+pub use line::{lines, Line, ParseError};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Kind {
+    /// Any text
+    Name,
+    /// An optionally signed decimal number
+    Int,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Piece {
+    Literal(String),
+    Field(Kind, String),
+}
+
+impl Piece {
+    fn expected(&self) -> String {
+        match self {
+            Piece::Literal(literal) => format!("'{}'", literal),
+            Piece::Field(_, expected) => expected.clone(),
+        }
+    }
+}
+
+/// A line format with literal words and fields.
+///
+/// Fields are written `{name}` or `{int}`, optionally with a description
+/// for errors like `{int:a distance}`. Whitespace separates words, so a
+/// field never spans several words, and it ends where the literal
+/// following it in its word starts. Two fields in a word need a literal
+/// between them.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Template {
+    words: Vec<Vec<Piece>>,
+}
+
+impl Template {
+    /// Compile `pattern`, panicking if it is malformed.
+    pub fn new(pattern: &str) -> Template {
+        let mut words = vec![Vec::new()];
+        let mut chars = pattern.chars();
+        let mut literal = String::new();
+        while let Some(c) = chars.next() {
+            match c {
+                '{' => {
+                    let field: String = chars.by_ref().take_while(|c| *c != '}').collect();
+                    let (kind, expected) = match field.split_once(':') {
+                        Some((kind, expected)) => (kind, expected),
+                        None => (field.as_str(), ""),
+                    };
+                    let (kind, default) = match kind {
+                        "name" => (Kind::Name, "a name"),
+                        "int" => (Kind::Int, "an integer"),
+                        _ => panic!("unknown field {{{}}} in template '{}'", field, pattern),
+                    };
+                    let word = words.last_mut().unwrap();
+                    if !literal.is_empty() {
+                        word.push(Piece::Literal(std::mem::take(&mut literal)));
+                    }
+                    assert!(!matches!(word.last(), Some(Piece::Field(..))), "adjacent fields in template '{}'", pattern);
+                    let expected = if expected.is_empty() { default } else { expected };
+                    word.push(Piece::Field(kind, expected.to_string()));
+                },
+                c if c.is_whitespace() => {
+                    if !literal.is_empty() {
+                        words.last_mut().unwrap().push(Piece::Literal(std::mem::take(&mut literal)));
+                    }
+                    if !words.last().unwrap().is_empty() {
+                        words.push(Vec::new());
+                    }
+                },
+                c => literal.push(c),
+            }
+        }
+        if !literal.is_empty() {
+            words.last_mut().unwrap().push(Piece::Literal(literal));
+        }
+        words.retain(|word| !word.is_empty());
+        Template { words }
+    }
+
+    /// Match `line` against the template.
+    pub fn parse<'a>(&'a self, line: Line<'a>) -> Result<Record<'a>, ParseError> {
+        let mut tokens = line.words();
+        let mut fields = Vec::new();
+        for pieces in self.words.iter() {
+            let mut rest = line.next(&mut tokens, &pieces[0].expected())?;
+            for (index, piece) in pieces.iter().enumerate() {
+                match piece {
+                    Piece::Literal(literal) => {
+                        rest = rest.strip_prefix(literal.as_str()).ok_or_else(|| line.error(rest, &piece.expected()))?;
+                    },
+                    Piece::Field(kind, expected) => {
+                        let end = match pieces.get(index + 1) {
+                            Some(Piece::Literal(next)) => rest.find(next.as_str()).unwrap_or(rest.len()),
+                            _ => rest.len(),
+                        };
+                        let (field, after) = rest.split_at(end);
+                        if field.is_empty() || *kind == Kind::Int && !is_integer(field) {
+                            return Err(line.error(field, expected));
+                        }
+                        fields.push((field, expected.as_str()));
+                        rest = after;
+                    },
+                }
+            }
+            if !rest.is_empty() {
+                return Err(line.error(rest, "whitespace"));
+            }
+        }
+        line.end(&mut tokens)?;
+        Ok(Record { line, fields })
+    }
+}
+
+fn is_integer(text: &str) -> bool {
+    let digits = text.strip_prefix(['-', '+']).unwrap_or(text);
+    !digits.is_empty() && digits.bytes().all(|b| b.is_ascii_digit())
+}
+
+/// Match `line` against the first fitting of the `templates`, returning its
+/// index along with the record.
+///
+/// If none fits, the error comes from the templates that matched the
+/// longest part of the line, e.g. "expected 'on' or 'off'".
+pub fn parse_any<'a>(templates: &'a [Template], line: Line<'a>) -> Result<(usize, Record<'a>), ParseError> {
+    let mut errors: Vec<ParseError> = Vec::new();
+    for (index, template) in templates.iter().enumerate() {
+        match template.parse(line) {
+            Ok(record) => return Ok((index, record)),
+            Err(error) => errors.push(error),
+        }
+    }
+    let column = errors.iter().map(|error| error.column).max().expect("no templates to match");
+    let mut furthest = errors.into_iter().filter(|error| error.column == column);
+    let mut error = furthest.next().unwrap();
+    let mut expected = vec![error.expected.clone()];
+    for other in furthest {
+        if !expected.contains(&other.expected) {
+            expected.push(other.expected);
+        }
+    }
+    error.expected = match expected.split_last() {
+        Some((last, [])) => last.clone(),
+        Some((last, others)) => format!("{} or {}", others.join(", "), last),
+        None => unreachable!(),
+    };
+    Err(error)
+}
+
+/// The fields of a line matched by a [`Template`], in order.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Record<'a> {
+    pub line: Line<'a>,
+    fields: Vec<(&'a str, &'a str)>,
+}
+
+impl<'a> Record<'a> {
+    /// Number of fields.
+    pub fn len(&self) -> usize {
+        self.fields.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.fields.is_empty()
+    }
+
+    pub fn text(&self, field: usize) -> &'a str {
+        self.fields[field].0
+    }
+
+    /// Parse `field`, failing with its description.
+    pub fn get<T: FromStr>(&self, field: usize) -> Result<T, ParseError> {
+        let (text, expected) = self.fields[field];
+        self.line.parse(text, expected)
+    }
+
+    /// Error pointing at `field`, for checks the template cannot express.
+    pub fn error(&self, field: usize, expected: &str) -> ParseError {
+        self.line.error(self.fields[field].0, expected)
+    }
+}
+
+/// The numbers in `text` like "-12", ignoring everything in between.
+pub fn number_tokens(text: &str) -> impl Iterator<Item = &str> {
+    let bytes = text.as_bytes();
+    let mut start = 0;
+    std::iter::from_fn(move || {
+        while start < bytes.len() {
+            let digits = if bytes[start] == b'-' { start + 1 } else { start };
+            if digits < bytes.len() && bytes[digits].is_ascii_digit() {
+                let end = digits + bytes[digits..].iter().take_while(|b| b.is_ascii_digit()).count();
+                let token = &text[start..end];
+                start = end;
+                return Some(token);
+            }
+            start += 1;
+        }
+        None
+    })
+}
+
+/// Exactly the `N` numbers of `line`, described by `expected`, ignoring the
+/// text around them.
+pub fn numbers<T: FromStr, const N: usize>(line: Line, expected: [&str; N]) -> Result<[T; N], ParseError> {
+    let mut tokens = number_tokens(line.text);
+    let mut values = Vec::with_capacity(N);
+    for expected in expected {
+        values.push(line.parse(line.next(&mut tokens, expected)?, expected)?);
+    }
+    if let Some(token) = tokens.next() {
+        return Err(line.error(token, "no more numbers"));
+    }
+    Ok(into_array(values))
+}
+
+/// Exactly `N` values in `text` split by `separator`, like "2x3x4".
+pub fn separated<'a, T: FromStr, const N: usize>(line: Line<'a>, text: &'a str, separator: &str,
+                                                 expected: &str) -> Result<[T; N], ParseError> {
+    let mut tokens = text.split(separator);
+    let mut values = Vec::with_capacity(N);
+    for _ in 0..N {
+        values.push(line.parse(line.next(&mut tokens, expected)?, expected)?);
+    }
+    line.end(&mut tokens)?;
+    Ok(into_array(values))
+}
+
+fn into_array<T, const N: usize>(values: Vec<T>) -> [T; N] {
+    values.try_into().unwrap_or_else(|_| unreachable!())
+}
+
+/// The pairs of a list like "cars: 9, akitas: 3", with `separator` between
+/// the pairs and `delimiter` between key and value.
+pub fn key_values<'a, T: FromStr>(line: Line<'a>, text: &'a str, separator: &str, delimiter: &str,
+                                  expected: &str) -> Result<Vec<(&'a str, T)>, ParseError> {
+    text.split(separator)
+        .map(|pair| {
+            let (key, value) = line.split_once(pair, delimiter)?;
+            Ok((key, line.parse(value, expected)?))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn line(text: &str) -> Line<'_> {
+        lines(text).next().unwrap()
+    }
+
+    #[test]
+    fn test_template() {
+        let template = Template::new("{name:a location} to {name:a location} = {int:a distance}");
+        let record = template.parse(line("London to Dublin = 464")).unwrap();
+        assert_eq!(3, record.len());
+        assert_eq!("Dublin", record.text(1));
+        assert_eq!(Ok(464usize), record.get(2));
+        assert_eq!(Err(ParseError::new(1, 20, "a distance", "464")), record.get::<u8>(2));
+
+        let error = |text| template.parse(line(text)).unwrap_err();
+        assert_eq!(ParseError::new(1, 8, "'to'", "from"), error("London from Dublin = 464"));
+        assert_eq!(ParseError::new(1, 20, "a distance", "far"), error("London to Dublin = far"));
+        assert_eq!(ParseError::new(1, 17, "'='", ""), error("London to Dublin"));
+        assert_eq!(ParseError::new(1, 24, "end of line", "km"), error("London to Dublin = 464 km"));
+    }
+
+    #[test]
+    fn test_fields_in_word() {
+        let template = Template::new("{name}: {int},{int} next to {name:a guest}.");
+        let record = template.parse(line("Alice: -3,+4 next to Bob.")).unwrap();
+        assert_eq!(vec!["Alice", "-3", "+4", "Bob"], (0..record.len()).map(|field| record.text(field)).collect::<Vec<_>>());
+        assert_eq!(Ok(-3), record.get::<i64>(1));
+        assert_eq!(ParseError::new(1, 22, "a guest", "Bob"), record.error(3, "a guest"));
+
+        let error = |text| template.parse(line(text)).unwrap_err();
+        assert_eq!(ParseError::new(1, 23, "'.'", ""), error("Alice: 3,4 next to Bob"));
+        assert_eq!(ParseError::new(1, 10, "an integer", ""), error("Alice: 3, next to Bob."));
+        assert_eq!(ParseError::new(1, 8, "an integer", "x"), error("Alice: x,4 next to Bob."));
+        assert_eq!(ParseError::new(1, 24, "whitespace", "!"), error("Alice: 3,4 next to Bob.!"));
+    }
+
+    #[test]
+    fn test_parse_any() {
+        let templates = [Template::new("turn on {int}"), Template::new("turn off {int}"), Template::new("toggle {int}")];
+        assert_eq!(2, parse_any(&templates, line("toggle 5")).unwrap().0);
+        let (index, record) = parse_any(&templates, line("turn off 7")).unwrap();
+        assert_eq!((1, Ok(7)), (index, record.get::<u8>(0)));
+        assert_eq!(Err(ParseError::new(1, 6, "'on' or 'off'", "of")), parse_any(&templates, line("turn of 7")));
+        assert_eq!(Err(ParseError::new(1, 1, "'turn' or 'toggle'", "switch")), parse_any(&templates, line("switch on 7")));
+        assert_eq!(Err(ParseError::new(1, 9, "an integer", "on")), parse_any(&templates, line("turn on on")));
+    }
+
+    #[test]
+    fn test_numbers() {
+        let text = "Comet can fly 14 km/s for 10 seconds, but then must rest for -127 seconds.";
+        assert_eq!(vec!["14", "10", "-127"], number_tokens(text).collect::<Vec<_>>());
+        let expected = ["a speed", "a flight duration", "a rest duration"];
+        assert_eq!(Ok([14, 10, -127]), numbers::<i64, 3>(line(text), expected));
+        assert_eq!(Err(ParseError::new(1, 62, "a rest duration", "-127")), numbers::<u64, 3>(line(text), expected));
+        assert_eq!(Err(ParseError::new(1, 8, "a rest duration", "")), numbers::<i64, 3>(line("1 and 2"), expected));
+        assert_eq!(Err(ParseError::new(1, 5, "no more numbers", "3")), numbers::<i64, 2>(line("1 2 3"), ["a", "b"]));
+    }
+
+    #[test]
+    fn test_lists() {
+        let present = line("2x3x4");
+        assert_eq!(Ok([2, 3, 4]), separated::<i64, 3>(present, present.text, "x", "a dimension"));
+        let short = line("1x");
+        assert_eq!(Err(ParseError::new(1, 3, "a dimension", "")), separated::<i64, 3>(short, short.text, "x", "a dimension"));
+
+        let aunt = line("Sue 1: cars: 9, akitas: 3");
+        assert_eq!(Ok(vec![("cars", 9), ("akitas", 3)]), key_values(aunt, &aunt.text[7..], ", ", ": ", "a number"));
+        let typo = line("cars: 9, akitas 3");
+        assert_eq!(Err(ParseError::new(1, 18, "': '", "")), key_values::<i64>(typo, typo.text, ", ", ": ", "a number"));
+    }
+}
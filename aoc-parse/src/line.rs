@@ -0,0 +1,183 @@
+use std::fmt::Display;
+use std::str::FromStr;
+
+/// A malformed puzzle input.
+///
+/// Points at the offending token so the runner can show a diagnostic like a
+/// compiler would, see [`ParseError::diagnostic`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    /// Line of the input, starting at 1
+    pub line: usize,
+    /// Column in characters, starting at 1
+    pub column: usize,
+    /// What the parser was looking for
+    pub expected: String,
+    /// What it found instead, empty at the end of a line
+    pub found: String,
+}
+
+impl ParseError {
+    pub fn new(line: usize, column: usize, expected: &str, found: &str) -> ParseError {
+        ParseError { line, column, expected: expected.to_string(), found: found.to_string() }
+    }
+
+    /// Render the error with the offending line of `contents` and a caret
+    /// under the token, `source` names the input (usually its path). A line
+    /// or column of 0 is taken as the first one.
+    pub fn diagnostic(&self, source: &str, contents: &str) -> String {
+        let text = contents.lines().nth(self.line.saturating_sub(1)).unwrap_or_default();
+        let number = self.line.to_string();
+        let margin = " ".repeat(number.len());
+        let carets = "^".repeat(self.found.chars().count().max(1));
+        format!("{}\n{}--> {}:{}:{}\n{} |\n{} | {}\n{} | {}{} expected {}",
+                self, margin, source, self.line, self.column,
+                margin,
+                number, text,
+                margin, " ".repeat(self.column.saturating_sub(1)), carets, self.expected)
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.found.is_empty() {
+            write!(f, "expected {}, found end of line", self.expected)
+        } else {
+            write!(f, "expected {}, found '{}'", self.expected, self.found)
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// A line of the puzzle input that knows where it is, so errors can point
+/// into it.
+///
+/// Tokens handed to its methods must be slices of [`Line::text`], otherwise
+/// errors point at the start of the line.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Line<'a> {
+    /// Line number, starting at 1
+    pub number: usize,
+    pub text: &'a str,
+}
+
+/// The lines of `input`, numbered from 1.
+pub fn lines(input: &str) -> impl Iterator<Item = Line<'_>> {
+    input.lines().enumerate().map(|(index, text)| Line { number: index + 1, text })
+}
+
+impl<'a> Line<'a> {
+    fn column(&self, token: &str) -> usize {
+        let offset = (token.as_ptr() as usize).wrapping_sub(self.text.as_ptr() as usize);
+        if offset <= self.text.len() && self.text.is_char_boundary(offset) {
+            self.text[..offset].chars().count() + 1
+        } else {
+            1
+        }
+    }
+
+    /// Error pointing at `token`.
+    pub fn error(&self, token: &str, expected: &str) -> ParseError {
+        ParseError::new(self.number, self.column(token), expected, token)
+    }
+
+    /// Error pointing just past the end of the line.
+    pub fn error_at_end(&self, expected: &str) -> ParseError {
+        ParseError::new(self.number, self.text.chars().count() + 1, expected, "")
+    }
+
+    /// The whitespace separated words of the line.
+    pub fn words(&self) -> std::str::SplitWhitespace<'a> {
+        self.text.split_whitespace()
+    }
+
+    /// Take the next token, failing at the end of the line.
+    pub fn next<I: Iterator<Item = &'a str>>(&self, tokens: &mut I, expected: &str) -> Result<&'a str, ParseError> {
+        tokens.next().ok_or_else(|| self.error_at_end(expected))
+    }
+
+    /// Parse `token`, failing with an error pointing at it.
+    pub fn parse<T: FromStr>(&self, token: &str, expected: &str) -> Result<T, ParseError> {
+        token.parse().map_err(|_| self.error(token, expected))
+    }
+
+    /// Check that `token` is exactly `literal`.
+    pub fn expect(&self, token: &str, literal: &str) -> Result<(), ParseError> {
+        if token == literal {
+            Ok(())
+        } else {
+            Err(self.error(token, &format!("'{}'", literal)))
+        }
+    }
+
+    /// Split `text` at the first `delimiter`, failing at the end of `text`.
+    pub fn split_once(&self, text: &'a str, delimiter: &str) -> Result<(&'a str, &'a str), ParseError> {
+        text.split_once(delimiter)
+            .ok_or_else(|| self.error(&text[text.len()..], &format!("'{}'", delimiter)))
+    }
+
+    /// Check that every character of the line satisfies `valid`.
+    pub fn validate(&self, valid: impl Fn(char) -> bool, expected: &str) -> Result<&'a str, ParseError> {
+        match self.text.char_indices().find(|(_, c)| !valid(*c)) {
+            Some((index, c)) => Err(self.error(&self.text[index..index + c.len_utf8()], expected)),
+            None => Ok(self.text),
+        }
+    }
+
+    /// Error if there are tokens left.
+    pub fn end<I: Iterator<Item = &'a str>>(&self, tokens: &mut I) -> Result<(), ParseError> {
+        match tokens.next() {
+            Some(token) => Err(self.error(token, "end of line")),
+            None => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_error_position() {
+        let line = lines("1x2x3\n4xyx6").nth(1).unwrap();
+        let mut dims = line.text.split('x');
+        assert_eq!(Ok(4), line.parse::<i64>(dims.next().unwrap(), "a number"));
+        let error = line.parse::<i64>(dims.next().unwrap(), "a number").unwrap_err();
+        assert_eq!(ParseError::new(2, 3, "a number", "y"), error);
+        assert_eq!("expected a number, found 'y'", error.to_string());
+    }
+
+    #[test]
+    fn test_error_at_end() {
+        let line = lines("Sue 1:").next().unwrap();
+        let mut words = line.words();
+        line.expect(line.next(&mut words, "'Sue'").unwrap(), "Sue").unwrap();
+        line.next(&mut words, "a number").unwrap();
+        let error = line.next(&mut words, "a fact").unwrap_err();
+        assert_eq!(ParseError::new(1, 7, "a fact", ""), error);
+        assert_eq!("expected a fact, found end of line", error.to_string());
+        assert_eq!(Err(ParseError::new(1, 7, "' => '", "")), line.split_once(line.text, " => "));
+    }
+
+    #[test]
+    fn test_validate() {
+        let line = lines("(()x)").next().unwrap();
+        assert_eq!(Err(ParseError::new(1, 4, "'(' or ')'", "x")), line.validate(|c| c == '(' || c == ')', "'(' or ')'"));
+        assert_eq!(Ok("(()x)"), line.validate(|c| c != ' ', "anything but a space"));
+    }
+
+    #[test]
+    fn test_diagnostic() {
+        let contents = "1x2x3\n4x5x6\n7x8xabc";
+        let error = lines(contents).nth(2).map(|line| line.error(&line.text[4..], "a number")).unwrap();
+        let expected = "expected a number, found 'abc'\n --> day02.txt:3:5\n  |\n3 | 7x8xabc\n  |     ^^^ expected a number";
+        assert_eq!(expected, error.diagnostic("day02.txt", contents));
+    }
+
+    #[test]
+    fn test_diagnostic_at_zero() {
+        let expected = "expected a number, found 'x'\n --> day02.txt:0:0\n  |\n0 | 1x2\n  | ^ expected a number";
+        assert_eq!(expected, ParseError::new(0, 0, "a number", "x").diagnostic("day02.txt", "1x2"));
+    }
+}
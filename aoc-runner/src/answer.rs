@@ -0,0 +1,137 @@
+use std::convert::Infallible;
+use std::fmt::Display;
+use std::str::FromStr;
+
+/// The answer to a part of a puzzle.
+///
+/// Most puzzles ask for a number, but some want a password or even a picture
+/// spanning several lines. Numbers compare equal regardless of their variant,
+/// so `Integer(5)` and `Unsigned(5)` are the same answer.
+#[derive(Debug, Clone, Eq)]
+pub enum Answer {
+    Integer(i64),
+    Unsigned(u128),
+    Text(String),
+    /// Multi-line answer, e.g. letters drawn on a grid
+    Block(String),
+}
+
+impl Answer {
+    /// Numeric value of the answer, if it has one.
+    fn as_number(&self) -> Option<i128> {
+        match self {
+            Answer::Integer(value) => Some(*value as i128),
+            Answer::Unsigned(value) => i128::try_from(*value).ok(),
+            _ => None,
+        }
+    }
+}
+
+impl PartialEq for Answer {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Answer::Text(a), Answer::Text(b)) => a == b,
+            (Answer::Block(a), Answer::Block(b)) => a == b,
+            (Answer::Unsigned(a), Answer::Unsigned(b)) => a == b,
+            (a, b) => a.as_number().is_some() && a.as_number() == b.as_number(),
+        }
+    }
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Answer::Integer(value) => write!(f, "{}", value),
+            Answer::Unsigned(value) => write!(f, "{}", value),
+            Answer::Text(text) => write!(f, "{}", text),
+            Answer::Block(block) => write!(f, "{}", block),
+        }
+    }
+}
+
+macro_rules! answer_from_signed {
+    ($($t:ty),*) => {
+        $(impl From<$t> for Answer {
+            fn from(value: $t) -> Self {
+                Answer::Integer(value as i64)
+            }
+        })*
+    };
+}
+
+macro_rules! answer_from_unsigned {
+    ($($t:ty),*) => {
+        $(impl From<$t> for Answer {
+            fn from(value: $t) -> Self {
+                Answer::Unsigned(value as u128)
+            }
+        })*
+    };
+}
+
+answer_from_signed!(i8, i16, i32, i64, isize);
+answer_from_unsigned!(u8, u16, u32, u64, u128, usize);
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        if value.contains('\n') {
+            Answer::Block(value)
+        } else {
+            Answer::Text(value)
+        }
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(value: &str) -> Self {
+        Answer::from(value.to_string())
+    }
+}
+
+/// Reads back an answer from its [`Display`] representation.
+impl FromStr for Answer {
+    type Err = Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if let Ok(value) = s.parse::<i64>() {
+            Ok(Answer::Integer(value))
+        } else if let Ok(value) = s.parse::<u128>() {
+            Ok(Answer::Unsigned(value))
+        } else {
+            Ok(Answer::from(s))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_numeric_equality() {
+        assert_eq!(Answer::Integer(5), Answer::Unsigned(5));
+        assert_eq!(Answer::from(5usize), Answer::from(5i64));
+        assert_ne!(Answer::Integer(-1), Answer::Unsigned(u128::MAX));
+        assert_ne!(Answer::Integer(5), Answer::Text(String::from("5")));
+    }
+
+    #[test]
+    fn test_from_string() {
+        assert_eq!(Answer::Text(String::from("abcdffaa")), Answer::from("abcdffaa"));
+        assert_eq!(Answer::Block(String::from("#.\n.#")), Answer::from("#.\n.#"));
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!("-3", Answer::from(-3).to_string());
+        assert_eq!("340282366920938463463374607431768211455", Answer::from(u128::MAX).to_string());
+        assert_eq!("#.\n.#", Answer::from("#.\n.#").to_string());
+    }
+
+    #[test]
+    fn test_roundtrip() {
+        for answer in [Answer::from(-3), Answer::from(u128::MAX), Answer::from("ghjaabcc"), Answer::from("#.\n.#")] {
+            assert_eq!(Ok(answer.clone()), answer.to_string().parse());
+        }
+    }
+}
@@ -0,0 +1,98 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use crate::Answer;
+
+/// Expected answers, keyed by day, input and part.
+///
+/// Everyone gets different puzzle inputs, so answers are stored per input
+/// (identified by [`input_hash`]). On disk this is a TOML file like
+///
+/// ```toml
+/// [day07.0cc175b9c0f1b6a831c399e269772661]
+/// part1 = "956"
+/// part2 = "40149"
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct AnswerRegistry {
+    path: PathBuf,
+    days: BTreeMap<String, BTreeMap<String, BTreeMap<String, String>>>,
+}
+
+/// Identifies a puzzle input independent of trailing whitespace.
+pub fn input_hash(contents: &str) -> String {
+    format!("{:x}", md5::compute(contents.trim_end()))
+}
+
+fn day_key(day: u8) -> String {
+    format!("day{:02}", day)
+}
+
+fn part_key(part: u8) -> String {
+    format!("part{}", part)
+}
+
+impl AnswerRegistry {
+    /// Load the registry from `path`. A missing file is an empty registry.
+    pub fn load(path: &Path) -> io::Result<AnswerRegistry> {
+        let days = match fs::read_to_string(path) {
+            Ok(contents) => toml::from_str(&contents)
+                .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))?,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => BTreeMap::new(),
+            Err(err) => return Err(err),
+        };
+        Ok(AnswerRegistry { path: path.to_path_buf(), days })
+    }
+
+    pub fn save(&self) -> io::Result<()> {
+        let contents = toml::to_string(&self.days)
+            .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))?;
+        fs::write(&self.path, contents)
+    }
+
+    pub fn get(&self, day: u8, input_hash: &str, part: u8) -> Option<Answer> {
+        self.days.get(&day_key(day))?
+                 .get(input_hash)?
+                 .get(&part_key(part))
+                 .map(|answer| answer.parse().unwrap())
+    }
+
+    pub fn record(&mut self, day: u8, input_hash: &str, part: u8, answer: &Answer) {
+        self.days.entry(day_key(day))
+                 .or_default()
+                 .entry(input_hash.to_string())
+                 .or_default()
+                 .insert(part_key(part), answer.to_string());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_input_hash() {
+        assert_eq!(input_hash("abc"), input_hash("abc\n"));
+        assert_ne!(input_hash("abc"), input_hash("abd"));
+    }
+
+    #[test]
+    fn test_record_and_reload() {
+        let path = std::env::temp_dir().join(format!("aoc-runner-answers-{}.toml", std::process::id()));
+        let mut registry = AnswerRegistry::load(&path).unwrap();
+        assert_eq!(None, registry.get(7, "abc", 1));
+
+        registry.record(7, "abc", 1, &Answer::from(956));
+        registry.record(11, "abc", 2, &Answer::from("abcdffbb"));
+        registry.save().unwrap();
+
+        let registry = AnswerRegistry::load(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+        assert_eq!(Some(Answer::from(956)), registry.get(7, "abc", 1));
+        assert_eq!(Some(Answer::from("abcdffbb")), registry.get(11, "abc", 2));
+        assert_eq!(None, registry.get(7, "abc", 2));
+        assert_eq!(None, registry.get(7, "abd", 1));
+    }
+}
@@ -0,0 +1,119 @@
+use std::time::{Duration, Instant};
+
+use crate::{ParseError, Solution};
+
+/// Summary of repeated timings of a part.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Stats {
+    pub runs: usize,
+    pub min: Duration,
+    pub median: Duration,
+    pub p95: Duration,
+    pub stddev: Duration,
+}
+
+impl Stats {
+    /// Summarize the given samples, `None` if there are none.
+    pub fn from_samples(samples: &[Duration]) -> Option<Stats> {
+        if samples.is_empty() {
+            return None;
+        }
+        let mut sorted = samples.to_vec();
+        sorted.sort();
+        let runs = sorted.len();
+        let median = if runs % 2 == 1 {
+            sorted[runs / 2]
+        } else {
+            (sorted[runs / 2 - 1] + sorted[runs / 2]) / 2
+        };
+        // nearest-rank percentile
+        let p95 = sorted[(runs * 95).div_ceil(100) - 1];
+
+        let secs: Vec<f64> = sorted.iter().map(Duration::as_secs_f64).collect();
+        let mean = secs.iter().sum::<f64>() / runs as f64;
+        let variance = if runs > 1 {
+            secs.iter().map(|s| (s - mean).powi(2)).sum::<f64>() / (runs - 1) as f64
+        } else {
+            0.0
+        };
+        Some(Stats { runs, min: sorted[0], median, p95, stddev: Duration::from_secs_f64(variance.sqrt()) })
+    }
+}
+
+/// Time `part` of `S` for `runs` runs after `warmup` untimed runs.
+///
+/// The input is parsed only once, so only the part itself is measured. The
+/// runs stop after the first one (warmup or not) taking longer than `limit`,
+/// so fewer than `runs` samples mean the part ran out of time.
+pub fn measure<S: Solution>(contents: &str, part: u8, warmup: usize, runs: usize, limit: Option<Duration>) -> Result<Vec<Duration>, ParseError> {
+    let input = S::parse(contents)?;
+    let part = crate::parts::<S>()[part as usize - 1];
+    let mut samples = Vec::new();
+    for run in 0..warmup + runs {
+        let partstart = Instant::now();
+        std::hint::black_box(part(&input));
+        let elapsed = partstart.elapsed();
+        if run >= warmup {
+            samples.push(elapsed);
+        }
+        if limit.is_some_and(|limit| elapsed > limit) {
+            break;
+        }
+    }
+    Ok(samples)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Answer;
+
+    #[test]
+    fn test_stats() {
+        let samples: Vec<Duration> = [5, 1, 4, 2, 3].iter().map(|ms| Duration::from_millis(*ms)).collect();
+        let stats = Stats::from_samples(&samples).unwrap();
+        assert_eq!(5, stats.runs);
+        assert_eq!(Duration::from_millis(1), stats.min);
+        assert_eq!(Duration::from_millis(3), stats.median);
+        assert_eq!(Duration::from_millis(5), stats.p95);
+        // sample standard deviation of 1..=5 is sqrt(2.5)
+        assert!((stats.stddev.as_secs_f64() * 1000.0 - 2.5f64.sqrt()).abs() < 1e-6);
+    }
+
+    struct Slow;
+
+    impl Solution for Slow {
+        const DAY: u8 = 0;
+        const TITLE: &'static str = "Slow";
+        type Input = ();
+
+        fn parse(_input: &str) -> Result<(), ParseError> {
+            Ok(())
+        }
+
+        fn part1(_input: &()) -> Option<Answer> {
+            None
+        }
+
+        fn part2(_input: &()) -> Option<Answer> {
+            std::thread::sleep(Duration::from_millis(20));
+            None
+        }
+    }
+
+    #[test]
+    fn test_measure_limit() {
+        assert_eq!(3, measure::<Slow>("", 1, 1, 3, Some(Duration::from_millis(10))).unwrap().len());
+        assert_eq!(0, measure::<Slow>("", 2, 1, 3, Some(Duration::from_millis(10))).unwrap().len());
+        assert_eq!(1, measure::<Slow>("", 2, 0, 3, Some(Duration::from_millis(10))).unwrap().len());
+        assert_eq!(3, measure::<Slow>("", 2, 0, 3, None).unwrap().len());
+    }
+
+    #[test]
+    fn test_stats_even() {
+        let samples = [Duration::from_millis(2), Duration::from_millis(4)];
+        let stats = Stats::from_samples(&samples).unwrap();
+        assert_eq!(Duration::from_millis(3), stats.median);
+        assert_eq!(None, Stats::from_samples(&[]));
+    }
+}
@@ -0,0 +1,85 @@
+use std::io;
+use std::path::{Path, PathBuf};
+use std::process;
+
+use clap::Parser;
+
+use crate::{input_path, run_days, watch, DayEntry, Format, InputSource, Instruments, Limits, Printer, Solution, WatchOptions};
+
+#[derive(Debug, Parser)]
+struct Args {
+    /// Path to the puzzle input, - to read it from stdin [default: inputs/dayNN.txt]
+    input: Option<PathBuf>,
+
+    /// Use the given text as puzzle input
+    #[arg(long, conflicts_with = "input")]
+    input_str: Option<String>,
+
+    /// Output format
+    #[arg(long, value_enum, default_value_t)]
+    format: Format,
+
+    #[command(flatten)]
+    limits: Limits,
+
+    #[command(flatten)]
+    instruments: Instruments,
+
+    #[command(flatten)]
+    watch: WatchOptions,
+}
+
+impl Args {
+    fn source(&self, day: u8) -> InputSource {
+        match (&self.input, &self.input_str) {
+            (_, Some(contents)) => InputSource::Inline(contents.clone()),
+            (Some(path), None) => InputSource::from_arg(path),
+            (None, None) => InputSource::File(input_path(Path::new("inputs"), day)),
+        }
+    }
+}
+
+/// Entry point for the binary of a single day.
+pub fn run<S: Solution>() {
+    let args = Args::parse();
+    if args.watch.watch {
+        let InputSource::File(path) = args.source(S::DAY) else {
+            eprintln!("--watch needs an input file");
+            process::exit(1);
+        };
+        let days = [(DayEntry::new::<S>(), path)];
+        let err = watch(&days, None, args.watch.fixtures.as_deref(), &args.limits, &args.instruments, &mut io::stdout().lock()).unwrap_err();
+        eprintln!("Stopped watching: {}", err);
+        process::exit(1);
+    }
+    let days = [(DayEntry::new::<S>(), args.source(S::DAY))];
+    let outcome = Printer::new(args.format, io::stdout().lock())
+        .and_then(|mut printer| {
+            let success = run_days(&days, None, &args.limits, &args.instruments, &mut printer)?;
+            printer.finish()?;
+            Ok(success)
+        });
+    match outcome {
+        Ok(true) => (),
+        Ok(false) => process::exit(1),
+        Err(err) => {
+            eprintln!("Could not write results: {}", err);
+            process::exit(1);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_input_source() {
+        let source = |args: &[&str]| Args::try_parse_from(args).unwrap().source(7);
+        assert_eq!(InputSource::File(PathBuf::from("inputs/day07.txt")), source(&["day07"]));
+        assert_eq!(InputSource::File(PathBuf::from("my.txt")), source(&["day07", "my.txt"]));
+        assert_eq!(InputSource::Stdin, source(&["day07", "-"]));
+        assert_eq!(InputSource::Inline(String::from("1 -> a")), source(&["day07", "--input-str", "1 -> a"]));
+        assert!(Args::try_parse_from(["day07", "--input-str", "1 -> a", "my.txt"]).is_err());
+    }
+}
@@ -0,0 +1,228 @@
+use std::collections::VecDeque;
+#[cfg(feature = "cli")]
+use std::io::{self, Write};
+use std::num::NonZeroUsize;
+use std::sync::mpsc;
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::{Duration, Instant};
+
+#[cfg(feature = "cli")]
+use clap::Args;
+
+use crate::{collect, profile, Answer, PartResult};
+#[cfg(feature = "cli")]
+use crate::{counting_installed, DayEntry, Failure, InputSource, Printer};
+
+/// A parsed input, ready to execute any part on it.
+pub type Prepared = Arc<dyn Fn(u8) -> Option<Answer> + Send + Sync>;
+
+/// How many parts run at once and how long they may take.
+#[derive(Debug, Clone, Copy, Default)]
+#[cfg_attr(feature = "cli", derive(Args))]
+pub struct Limits {
+    /// Number of parts to run at the same time [default: number of CPUs]
+    #[cfg_attr(feature = "cli", arg(long))]
+    pub jobs: Option<NonZeroUsize>,
+
+    /// Give up on a part after this many seconds and report it as TIMEOUT
+    #[cfg_attr(feature = "cli", arg(long, value_parser = parse_timeout))]
+    pub timeout: Option<Duration>,
+}
+
+/// Parse `--timeout`, a positive number of seconds.
+pub fn parse_timeout(seconds: &str) -> Result<Duration, String> {
+    match seconds.parse::<f64>() {
+        Ok(seconds) if seconds > 0.0 && seconds.is_finite() => Ok(Duration::from_secs_f64(seconds)),
+        _ => Err(format!("'{}' is not a positive number of seconds", seconds)),
+    }
+}
+
+impl Limits {
+    fn jobs(&self) -> usize {
+        self.jobs.or_else(|| thread::available_parallelism().ok()).map_or(1, NonZeroUsize::get)
+    }
+}
+
+/// What to record about each part besides its answer and runtime.
+#[derive(Debug, Clone, Copy, Default)]
+#[cfg_attr(feature = "cli", derive(Args))]
+pub struct Instruments {
+    /// Explain how the answers were found, for the days that support it
+    #[cfg_attr(feature = "cli", arg(long))]
+    pub explain: bool,
+
+    /// Count the allocations and the peak heap bytes (not the resident memory)
+    /// of each part, running one part at a time
+    // the counters are shared, a part abandoned after its timeout would keep
+    // counting into the next one
+    #[cfg_attr(feature = "cli", arg(long, conflicts_with = "timeout"))]
+    pub profile_mem: bool,
+}
+
+/// Execute one part in its own thread, so it can be abandoned once it runs
+/// out of time. Rust cannot stop a thread, an abandoned part keeps running in
+/// the background until the process exits.
+fn execute_part(prepared: &Prepared, part: u8, timeout: Option<Duration>, instruments: Instruments) -> PartResult {
+    let (sender, receiver) = mpsc::channel();
+    let prepared = Arc::clone(prepared);
+    thread::spawn(move || {
+        let partstart = Instant::now();
+        let ((answer, trace), memory) = profile(instruments.profile_mem, || collect(instruments.explain, || prepared(part)));
+        // nobody is listening anymore if the part timed out
+        let _ = sender.send((answer, partstart.elapsed(), trace, memory));
+    });
+    let received = match timeout {
+        Some(timeout) => receiver.recv_timeout(timeout),
+        None => receiver.recv().map_err(mpsc::RecvTimeoutError::from),
+    };
+    match received {
+        Ok((answer, elapsed, trace, memory)) => PartResult { part, answer, elapsed, timed_out: false, panicked: false, trace, memory },
+        Err(mpsc::RecvTimeoutError::Timeout) => {
+            PartResult { part, answer: None, elapsed: timeout.unwrap_or_default(), timed_out: true, panicked: false, trace: Vec::new(), memory: None }
+        },
+        // the part panicked, the panic message is already on stderr
+        Err(mpsc::RecvTimeoutError::Disconnected) => {
+            PartResult { part, answer: None, elapsed: Duration::ZERO, timed_out: false, panicked: true, trace: Vec::new(), memory: None }
+        },
+    }
+}
+
+/// Execute `tasks` on `limits.jobs` workers, calling `on_result` on the
+/// calling thread with the index of each task as soon as it is done. The
+/// results carry what `instruments` asks for, profiling memory executes the
+/// parts one at a time so their allocations can be told apart.
+pub fn execute(tasks: Vec<(Prepared, u8)>, limits: &Limits, instruments: &Instruments, mut on_result: impl FnMut(usize, PartResult)) {
+    let count = tasks.len();
+    let queue = Arc::new(Mutex::new(tasks.into_iter().enumerate().collect::<VecDeque<_>>()));
+    let (sender, receiver) = mpsc::channel();
+    let jobs = if instruments.profile_mem { 1 } else { limits.jobs() };
+    let instruments = *instruments;
+    for _ in 0..jobs.min(count) {
+        let queue = Arc::clone(&queue);
+        let sender = sender.clone();
+        let timeout = limits.timeout;
+        thread::spawn(move || loop {
+            let Some((index, (prepared, part))) = queue.lock().unwrap().pop_front() else {
+                break;
+            };
+            if sender.send((index, execute_part(&prepared, part, timeout, instruments))).is_err() {
+                break;
+            }
+        });
+    }
+    drop(sender);
+    for (index, result) in receiver.iter().take(count) {
+        on_result(index, result);
+    }
+}
+
+/// Solve `days`, running their parts in parallel within `limits`, and print
+/// the results in the order of `days`.
+///
+/// Returns false if an input could not be read or parsed, or a part timed out
+/// or panicked. Fails if memory is to be profiled but the binary did not
+/// install the [`CountingAllocator`](crate::CountingAllocator).
+#[cfg(feature = "cli")]
+pub fn run_days<W: Write>(days: &[(DayEntry, InputSource)], part: Option<u8>, limits: &Limits, instruments: &Instruments,
+                          printer: &mut Printer<W>) -> io::Result<bool> {
+    if instruments.profile_mem && !counting_installed() {
+        return Err(io::Error::other("--profile-mem needs the counting allocator, see aoc_runner::count_allocations!"));
+    }
+    let parts: Vec<u8> = (1..=2).filter(|p| part.is_none_or(|part| part == *p)).collect();
+
+    let mut outcomes: Vec<Result<Vec<PartResult>, Failure>> = Vec::new();
+    let mut tasks = Vec::new();
+    let mut owners = Vec::new();
+    for (index, (entry, source)) in days.iter().enumerate() {
+        match entry.prepare(source) {
+            Ok(prepared) => {
+                for part in &parts {
+                    tasks.push((Arc::clone(&prepared), *part));
+                    owners.push(index);
+                }
+                outcomes.push(Ok(Vec::new()));
+            },
+            Err(failure) => outcomes.push(Err(failure)),
+        }
+    }
+
+    let mut success = true;
+    let mut printed = 0;
+    let mut result = Ok(());
+    let mut print_finished = |outcomes: &mut [Result<Vec<PartResult>, Failure>]| -> io::Result<()> {
+        while printed < days.len() {
+            let outcome = &mut outcomes[printed];
+            if outcome.as_ref().is_ok_and(|results| results.len() < parts.len()) {
+                break;
+            }
+            if let Ok(results) = outcome {
+                results.sort_by_key(|result| result.part);
+            }
+            let (entry, source) = &days[printed];
+            printer.print_day(entry.day, entry.title, &source.to_string(), &parts, outcome)?;
+            success &= outcome.as_ref().is_ok_and(|results| results.iter().all(|result| !result.timed_out && !result.panicked));
+            printed += 1;
+        }
+        Ok(())
+    };
+    // days that failed to prepare before the first task finishes
+    print_finished(&mut outcomes)?;
+    execute(tasks, limits, instruments, |task, part_result| {
+        if let Ok(results) = &mut outcomes[owners[task]] {
+            results.push(part_result);
+        }
+        if result.is_ok() {
+            result = print_finished(&mut outcomes);
+        }
+    });
+    result?;
+    Ok(success)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn prepared() -> Prepared {
+        Arc::new(|part| {
+            if part == 2 {
+                thread::sleep(Duration::from_secs(10));
+            }
+            Some(Answer::from(part))
+        })
+    }
+
+    #[test]
+    fn test_parse_timeout() {
+        assert_eq!(Ok(Duration::from_millis(1500)), parse_timeout("1.5"));
+        assert!(parse_timeout("0").is_err());
+        assert!(parse_timeout("soon").is_err());
+    }
+
+    #[test]
+    fn test_timeout() {
+        let limits = Limits { jobs: NonZeroUsize::new(2), timeout: Some(Duration::from_millis(50)) };
+        let mut results = vec![None, None];
+        execute(vec![(prepared(), 1), (prepared(), 2)], &limits, &Instruments::default(), |index, result| results[index] = Some(result));
+        let results: Vec<PartResult> = results.into_iter().flatten().collect();
+        assert_eq!(2, results.len());
+        assert_eq!(Some(Answer::from(1)), results[0].answer);
+        assert!(!results[0].timed_out);
+        assert_eq!(None, results[1].answer);
+        assert!(results[1].timed_out);
+    }
+
+    #[test]
+    fn test_panic() {
+        let panicking: Prepared = Arc::new(|part| if part == 2 { panic!("part 2 is broken") } else { Some(Answer::from(part)) });
+        let mut results = vec![None, None];
+        execute(vec![(Arc::clone(&panicking), 1), (panicking, 2)], &Limits::default(), &Instruments::default(), |index, result| results[index] = Some(result));
+        let results: Vec<PartResult> = results.into_iter().flatten().collect();
+        assert_eq!(Some(Answer::from(1)), results[0].answer);
+        assert!(!results[0].panicked);
+        assert_eq!(None, results[1].answer);
+        assert!(results[1].panicked);
+        assert!(!results[1].timed_out);
+    }
+}
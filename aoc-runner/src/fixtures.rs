@@ -0,0 +1,154 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use crate::{Answer, DayEntry, Limits};
+
+/// An example input together with the answers it has to give.
+///
+/// Fixtures live in `<root>/dayNN/`, every `<name>.txt` input next to a
+/// `<name>.expected` file with the answers of one or both parts:
+///
+/// ```toml
+/// part1 = "605"
+/// part2 = "982"
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Fixture {
+    pub day: u8,
+    pub name: String,
+    pub input: String,
+    /// Parts without an answer here are not run
+    pub expected: BTreeMap<u8, Answer>,
+}
+
+/// Directory holding the fixtures of `day`.
+pub fn fixture_dir(root: &Path, day: u8) -> PathBuf {
+    root.join(format!("day{:02}", day))
+}
+
+fn invalid(message: String) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message)
+}
+
+fn parse_expected(path: &Path, contents: &str) -> io::Result<BTreeMap<u8, Answer>> {
+    let parts: BTreeMap<String, String> = toml::from_str(contents)
+        .map_err(|err| invalid(format!("{}: {}", path.display(), err)))?;
+    parts.into_iter().map(|(key, answer)| match key.as_str() {
+        "part1" => Ok((1, answer.parse().unwrap())),
+        "part2" => Ok((2, answer.parse().unwrap())),
+        _ => Err(invalid(format!("{}: expected 'part1' or 'part2', found '{}'", path.display(), key))),
+    }).collect()
+}
+
+/// Every fixture of `day` below `root`, sorted by name. A day without a
+/// directory has no fixtures, an input without answers is an error.
+pub fn load_fixtures(root: &Path, day: u8) -> io::Result<Vec<Fixture>> {
+    let dir = fixture_dir(root, day);
+    let entries = match fs::read_dir(&dir) {
+        Ok(entries) => entries,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(err) => return Err(err),
+    };
+    let mut fixtures = Vec::new();
+    for entry in entries {
+        let path = entry?.path();
+        if path.extension().is_none_or(|extension| extension != "txt") {
+            continue;
+        }
+        let expected_path = path.with_extension("expected");
+        let expected = fs::read_to_string(&expected_path)
+            .map_err(|err| io::Error::new(err.kind(), format!("{}: {}", expected_path.display(), err)))?;
+        fixtures.push(Fixture {
+            day,
+            name: path.file_stem().unwrap().to_string_lossy().into_owned(),
+            input: fs::read_to_string(&path)?,
+            expected: parse_expected(&expected_path, &expected)?,
+        });
+    }
+    fixtures.sort_by(|a, b| a.name.cmp(&b.name));
+    Ok(fixtures)
+}
+
+impl Fixture {
+    /// Run the parts of `entry` with an expected answer within `limits`,
+    /// describing every answer that differs.
+    pub fn check(&self, entry: &DayEntry, limits: &Limits) -> Vec<String> {
+        let mut mismatches = Vec::new();
+        for (&part, expected) in &self.expected {
+            let result = match entry.solve_within(&self.input, Some(part), limits) {
+                Ok(results) => results.into_iter().next(),
+                Err(error) => {
+                    mismatches.push(format!("day{:02}/{}: line {}, column {}: {}", self.day, self.name, error.line, error.column, error));
+                    break;
+                }
+            };
+            if let Some(failure) = result.as_ref().and_then(|result| result.failure()) {
+                mismatches.push(format!("day{:02}/{} part {}: expected {}, got {}", self.day, self.name, part, expected, failure));
+                continue;
+            }
+            match result.and_then(|result| result.answer) {
+                Some(answer) if answer == *expected => (),
+                Some(answer) => mismatches.push(format!("day{:02}/{} part {}: expected {}, got {}", self.day, self.name, part, expected, answer)),
+                None => mismatches.push(format!("day{:02}/{} part {}: expected {}, got no result", self.day, self.name, part, expected)),
+            }
+        }
+        mismatches
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ParseError, Solution};
+
+    struct Length;
+
+    impl Solution for Length {
+        const DAY: u8 = 1;
+        const TITLE: &'static str = "Length";
+        type Input = String;
+
+        fn parse(input: &str) -> Result<String, ParseError> {
+            Ok(input.trim_end().to_string())
+        }
+
+        fn part1(input: &String) -> Option<Answer> {
+            Some(input.len().into())
+        }
+
+        fn part2(_: &String) -> Option<Answer> {
+            None
+        }
+    }
+
+    #[test]
+    fn test_load_and_check() {
+        let root = std::env::temp_dir().join(format!("aoc-runner-fixtures-{}", std::process::id()));
+        let dir = fixture_dir(&root, 1);
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join("short.txt"), "abc\n").unwrap();
+        fs::write(dir.join("short.expected"), "part1 = \"3\"\n").unwrap();
+        fs::write(dir.join("long.txt"), "abcdef\n").unwrap();
+        fs::write(dir.join("long.expected"), "part1 = \"5\"\npart2 = \"6\"\n").unwrap();
+        fs::write(dir.join("notes.md"), "not a fixture").unwrap();
+
+        let fixtures = load_fixtures(&root, 1).unwrap();
+        let names: Vec<&str> = fixtures.iter().map(|fixture| fixture.name.as_str()).collect();
+        assert_eq!(vec!["long", "short"], names);
+        assert_eq!(BTreeMap::from([(1, Answer::from(3))]), fixtures[1].expected);
+
+        let entry = DayEntry::new::<Length>();
+        assert!(fixtures[1].check(&entry, &Limits::default()).is_empty());
+        assert_eq!(vec!["day01/long part 1: expected 5, got 6", "day01/long part 2: expected 6, got no result"], fixtures[0].check(&entry, &Limits::default()));
+
+        fs::write(dir.join("broken.txt"), "").unwrap();
+        assert!(load_fixtures(&root, 1).is_err());
+        fs::write(dir.join("broken.expected"), "part3 = \"1\"").unwrap();
+        assert!(load_fixtures(&root, 1).is_err());
+        fs::remove_dir_all(&root).unwrap();
+
+        assert_eq!(Vec::<Fixture>::new(), load_fixtures(&root, 2).unwrap());
+    }
+}
@@ -0,0 +1,99 @@
+use std::fmt::Display;
+use std::fs;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+
+use crate::ParseError;
+
+/// Where the puzzle input comes from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputSource {
+    File(PathBuf),
+    Stdin,
+    /// Input given directly on the command line
+    Inline(String),
+}
+
+/// Conventional location of the input of `day` within `inputs`.
+pub fn input_path(inputs: &Path, day: u8) -> PathBuf {
+    inputs.join(format!("day{:02}.txt", day))
+}
+
+impl InputSource {
+    /// Interpret a command line argument, `-` meaning stdin.
+    pub fn from_arg(arg: &Path) -> InputSource {
+        if arg == Path::new("-") {
+            InputSource::Stdin
+        } else {
+            InputSource::File(arg.to_path_buf())
+        }
+    }
+
+    pub fn read(&self) -> io::Result<String> {
+        match self {
+            InputSource::File(path) => fs::read_to_string(path),
+            InputSource::Stdin => {
+                let mut contents = String::new();
+                io::stdin().read_to_string(&mut contents)?;
+                Ok(contents)
+            },
+            InputSource::Inline(contents) => Ok(contents.clone()),
+        }
+    }
+}
+
+/// Names the input in messages and reports.
+impl Display for InputSource {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InputSource::File(path) => write!(f, "{}", path.display()),
+            InputSource::Stdin => write!(f, "<stdin>"),
+            InputSource::Inline(_) => write!(f, "<input-str>"),
+        }
+    }
+}
+
+/// Why a day produced no results.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Failure {
+    /// The input could not be read
+    Read(String),
+    /// The input is malformed, `diagnostic` shows where
+    Parse { error: ParseError, diagnostic: String },
+}
+
+impl Failure {
+    /// See [`ParseError::diagnostic`] for `source` and `contents`.
+    pub fn parse(error: ParseError, source: &str, contents: &str) -> Failure {
+        let diagnostic = error.diagnostic(source, contents);
+        Failure::Parse { error, diagnostic }
+    }
+}
+
+impl Display for Failure {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Failure::Read(message) => write!(f, "{}", message),
+            Failure::Parse { error, .. } => write!(f, "line {}, column {}: {}", error.line, error.column, error),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_arg() {
+        assert_eq!(InputSource::Stdin, InputSource::from_arg(Path::new("-")));
+        assert_eq!(InputSource::File(PathBuf::from("day07.txt")), InputSource::from_arg(Path::new("day07.txt")));
+        assert_eq!(PathBuf::from("inputs/day07.txt"), input_path(Path::new("inputs"), 7));
+    }
+
+    #[test]
+    fn test_inline() {
+        let source = InputSource::Inline(String::from("1x1x10"));
+        assert_eq!("1x1x10", source.read().unwrap());
+        assert_eq!("<input-str>", source.to_string());
+    }
+}
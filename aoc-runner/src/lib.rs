@@ -0,0 +1,273 @@
+//! Shared harness for the daily solutions.
+//!
+//! Every day implements [`Solution`] and hands itself to [`run`], which takes
+//! care of argument handling, reading the puzzle input, timing and output.
+//!
+//! The command line, the output formats, watching and the answer and fixture
+//! files need the `cli` feature (on by default); without it the crate only
+//! has the traits and the execution of parts, for days used as libraries.
+
+mod answer;
+#[cfg(feature = "cli")]
+mod answers;
+mod bench;
+#[cfg(feature = "cli")]
+mod cli;
+mod exec;
+#[cfg(feature = "cli")]
+mod fixtures;
+mod input;
+mod memory;
+#[cfg(feature = "cli")]
+mod output;
+mod trace;
+#[cfg(feature = "cli")]
+mod watch;
+
+use std::fmt::Display;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+pub use aoc_parse::{lines, Line, ParseError};
+pub use answer::Answer;
+#[cfg(feature = "cli")]
+pub use answers::{input_hash, AnswerRegistry};
+pub use bench::{measure, Stats};
+#[cfg(feature = "cli")]
+pub use cli::run;
+#[cfg(feature = "cli")]
+pub use exec::run_days;
+pub use exec::{execute, parse_timeout, Instruments, Limits, Prepared};
+#[cfg(feature = "cli")]
+pub use fixtures::{fixture_dir, load_fixtures, Fixture};
+pub use input::{input_path, Failure, InputSource};
+pub use memory::{counting_installed, profile, CountingAllocator, MemoryStats};
+#[cfg(feature = "cli")]
+pub use output::{Format, Printer};
+pub use trace::{collect, trace, Event};
+#[cfg(feature = "cli")]
+pub use watch::{watch, WatchOptions};
+
+/// A single day of the event.
+///
+/// The input is parsed once and then handed to both parts, so expensive
+/// preprocessing does not have to be repeated (or timed twice).
+pub trait Solution {
+    /// Day of the event (1-25)
+    const DAY: u8;
+    /// Title of the puzzle
+    const TITLE: &'static str;
+
+    /// Parsed representation of the puzzle input, shared by the parts
+    /// running in parallel
+    type Input: Send + Sync + 'static;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError>;
+    fn part1(input: &Self::Input) -> Option<Answer>;
+    fn part2(input: &Self::Input) -> Option<Answer>;
+}
+
+/// Outcome of executing a single part.
+#[derive(Debug, Clone)]
+pub struct PartResult {
+    pub part: u8,
+    pub answer: Option<Answer>,
+    pub elapsed: Duration,
+    /// The part was abandoned after running for `elapsed`
+    pub timed_out: bool,
+    /// The part panicked, the panic message went to stderr
+    pub panicked: bool,
+    /// How the answer was found, only collected with `--explain`
+    pub trace: Vec<Event>,
+    /// Heap usage of the part, only measured with `--profile-mem`
+    pub memory: Option<MemoryStats>,
+}
+
+impl Display for PartResult {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.timed_out {
+            return write!(f, "Part {}: TIMEOUT\t(>{:?})", self.part, self.elapsed);
+        }
+        if let Some(failure) = self.failure() {
+            return write!(f, "Part {}: {}", self.part, failure);
+        }
+        match &self.answer {
+            // multi-line answers start on their own line
+            Some(Answer::Block(block)) => write!(f, "Part {}:\t({})\n{}", self.part, self.cost(), block),
+            Some(answer) => write!(f, "Part {}: {}\t({})", self.part, answer, self.cost()),
+            None => write!(f, "Part {}: No result", self.part),
+        }
+    }
+}
+
+impl PartResult {
+    /// "TIMEOUT" or "PANIC" if the part did not finish.
+    pub fn failure(&self) -> Option<&'static str> {
+        if self.timed_out {
+            Some("TIMEOUT")
+        } else if self.panicked {
+            Some("PANIC")
+        } else {
+            None
+        }
+    }
+
+    /// Runtime of the part, and its heap usage if profiled.
+    pub(crate) fn cost(&self) -> String {
+        match &self.memory {
+            Some(memory) => format!("{:?}, {}", self.elapsed, memory),
+            None => format!("{:?}", self.elapsed),
+        }
+    }
+}
+
+/// Parse the puzzle input and execute the parts of `S` on it.
+///
+/// If `part` is given, only that part is executed.
+pub fn solve<S: Solution>(contents: &str, part: Option<u8>) -> Result<Vec<PartResult>, ParseError> {
+    let input = S::parse(contents)?;
+    // later parts may follow, so we loop over the part functions
+    let results = parts::<S>().iter()
+         .enumerate()
+         .map(|(index, part)| (index as u8 + 1, part))
+         .filter(|(index, _)| part.is_none_or(|part| part == *index))
+         .map(|(index, part)| {
+             let partstart = Instant::now();
+             let answer = part(&input);
+             PartResult { part: index, answer, elapsed: partstart.elapsed(), timed_out: false, panicked: false, trace: Vec::new(), memory: None }
+         })
+         .collect();
+    Ok(results)
+}
+
+type Part<S> = fn(&<S as Solution>::Input) -> Option<Answer>;
+
+fn parts<S: Solution>() -> [Part<S>; 2] {
+    [S::part1, S::part2]
+}
+
+fn prepare<S: Solution>(contents: &str) -> Result<Prepared, ParseError> {
+    let input = Arc::new(S::parse(contents)?);
+    let parts = parts::<S>();
+    Ok(Arc::new(move |part: u8| parts[part as usize - 1](&input)))
+}
+
+type Preparer = fn(&str) -> Result<Prepared, ParseError>;
+type Solver = fn(&str, Option<u8>) -> Result<Vec<PartResult>, ParseError>;
+type Bencher = fn(&str, u8, usize, usize, Option<Duration>) -> Result<Vec<Duration>, ParseError>;
+
+/// Type-erased handle on a [`Solution`].
+///
+/// Days differ in their input types, so they cannot be collected directly.
+/// An entry only remembers the metadata and how to solve the day.
+#[derive(Clone, Copy)]
+pub struct DayEntry {
+    pub day: u8,
+    pub title: &'static str,
+    solver: Solver,
+    preparer: Preparer,
+    bencher: Bencher,
+}
+
+impl DayEntry {
+    pub const fn new<S: Solution>() -> DayEntry {
+        DayEntry { day: S::DAY, title: S::TITLE, solver: solve::<S>, preparer: prepare::<S>, bencher: measure::<S> }
+    }
+
+    pub fn solve(&self, contents: &str, part: Option<u8>) -> Result<Vec<PartResult>, ParseError> {
+        (self.solver)(contents, part)
+    }
+
+    /// Read and parse the input from `source`, so the parts can be executed
+    /// separately, e.g. by [`execute`].
+    pub fn prepare(&self, source: &InputSource) -> Result<Prepared, Failure> {
+        let contents = source.read()
+            .map_err(|err| Failure::Read(format!("could not read {}: {}", source, err)))?;
+        (self.preparer)(&contents)
+            .map_err(|error| Failure::parse(error, &source.to_string(), &contents))
+    }
+
+    /// Like [`DayEntry::solve`], but the parts run in parallel within
+    /// `limits`, see [`execute`].
+    pub fn solve_within(&self, contents: &str, part: Option<u8>, limits: &Limits) -> Result<Vec<PartResult>, ParseError> {
+        let prepared = (self.preparer)(contents)?;
+        let tasks = (1..=2).filter(|p| part.is_none_or(|part| part == *p)).map(|part| (Arc::clone(&prepared), part)).collect();
+        let mut results = Vec::new();
+        execute(tasks, limits, &Instruments::default(), |_, result| results.push(result));
+        results.sort_by_key(|result| result.part);
+        Ok(results)
+    }
+
+    /// See [`measure`].
+    pub fn measure(&self, contents: &str, part: u8, warmup: usize, runs: usize, limit: Option<Duration>) -> Result<Vec<Duration>, ParseError> {
+        (self.bencher)(contents, part, warmup, runs, limit)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Sum;
+
+    impl Solution for Sum {
+        const DAY: u8 = 0;
+        const TITLE: &'static str = "Test";
+        type Input = Vec<i64>;
+
+        fn parse(input: &str) -> Result<Vec<i64>, ParseError> {
+            lines(input).map(|line| line.parse(line.text, "a number")).collect()
+        }
+
+        fn part1(input: &Vec<i64>) -> Option<Answer> {
+            Some(input.iter().sum::<i64>().into())
+        }
+
+        fn part2(_input: &Vec<i64>) -> Option<Answer> {
+            None
+        }
+    }
+
+    #[test]
+    fn test_solve() {
+        let results = solve::<Sum>("1\n2\n3", None).unwrap();
+        assert_eq!(2, results.len());
+        assert_eq!(Some(Answer::Integer(6)), results[0].answer);
+        assert_eq!(None, results[1].answer);
+        assert_eq!("Part 2: No result", results[1].to_string());
+    }
+
+    #[test]
+    fn test_block_result() {
+        let result = PartResult { part: 1, answer: Some(Answer::from("#.\n.#")), elapsed: Duration::ZERO, timed_out: false, panicked: false, trace: Vec::new(), memory: None };
+        assert_eq!("Part 1:\t(0ns)\n#.\n.#", result.to_string());
+    }
+
+    #[test]
+    fn test_memory_result() {
+        let memory = MemoryStats { allocations: 2, allocated_bytes: 2048, peak_bytes: 1024 };
+        let result = PartResult { part: 1, answer: Some(Answer::from(5)), elapsed: Duration::from_nanos(42), timed_out: false, panicked: false, trace: Vec::new(), memory: Some(memory) };
+        assert_eq!("Part 1: 5\t(42ns, 2 allocations, 2.0 KiB allocated, 1.0 KiB peak)", result.to_string());
+    }
+
+    #[test]
+    fn test_solve_single_part() {
+        let results = DayEntry::new::<Sum>().solve("1\n2\n3", Some(1)).unwrap();
+        assert_eq!(1, results.len());
+        assert_eq!(1, results[0].part);
+        assert_eq!(Some(Answer::Integer(6)), results[0].answer);
+    }
+
+    #[test]
+    fn test_solve_within() {
+        let results = DayEntry::new::<Sum>().solve_within("1\n2\n3", None, &Limits::default()).unwrap();
+        let answers: Vec<Option<Answer>> = results.into_iter().map(|result| result.answer).collect();
+        assert_eq!(vec![Some(Answer::Integer(6)), None], answers);
+        assert!(DayEntry::new::<Sum>().solve_within("x", None, &Limits::default()).is_err());
+    }
+
+    #[test]
+    fn test_solve_malformed() {
+        assert_eq!(Err(ParseError::new(2, 1, "a number", "x")), solve::<Sum>("1\nx\n3", None).map(|_| ()));
+    }
+}
@@ -0,0 +1,178 @@
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::fmt::Display;
+use std::sync::atomic::{AtomicBool, AtomicI64, AtomicU64, Ordering};
+
+use serde::Serialize;
+
+/// Heap usage of a part, see `--profile-mem`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+pub struct MemoryStats {
+    pub allocations: u64,
+    /// Bytes requested over all allocations, including those freed again
+    pub allocated_bytes: u64,
+    /// Most bytes the part had allocated at the same time
+    pub peak_bytes: u64,
+}
+
+/// Human readable size, e.g. "1.5 MiB".
+fn bytes(count: u64) -> String {
+    const UNITS: [&str; 4] = ["KiB", "MiB", "GiB", "TiB"];
+    if count < 1024 {
+        return format!("{} B", count);
+    }
+    let mut size = count as f64 / 1024.0;
+    let mut unit = 0;
+    while size >= 1024.0 && unit + 1 < UNITS.len() {
+        size /= 1024.0;
+        unit += 1;
+    }
+    format!("{:.1} {}", size, UNITS[unit])
+}
+
+impl Display for MemoryStats {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} allocations, {} allocated, {} peak", self.allocations, bytes(self.allocated_bytes), bytes(self.peak_bytes))
+    }
+}
+
+static COUNTING: AtomicBool = AtomicBool::new(false);
+static ALLOCATIONS: AtomicU64 = AtomicU64::new(0);
+static ALLOCATED: AtomicU64 = AtomicU64::new(0);
+// relative to the start of the profile, freeing older memory makes it negative
+static CURRENT: AtomicI64 = AtomicI64::new(0);
+static PEAK: AtomicI64 = AtomicI64::new(0);
+
+/// The system allocator, counting allocations while a part is profiled.
+///
+/// The counters are shared by all threads, so parts are only profiled one
+/// at a time (see [`execute`](crate::execute)). A library must not pick the
+/// allocator of its users, binaries install it with [`count_allocations!`]
+/// (or [`main!`](crate::main!), which does so too).
+pub struct CountingAllocator;
+
+impl CountingAllocator {
+    fn grow(&self, allocated: usize, freed: usize) {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        ALLOCATED.fetch_add(allocated as u64, Ordering::Relaxed);
+        let current = CURRENT.fetch_add(allocated as i64 - freed as i64, Ordering::Relaxed) + allocated as i64 - freed as i64;
+        PEAK.fetch_max(current, Ordering::Relaxed);
+    }
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() && COUNTING.load(Ordering::Relaxed) {
+            self.grow(layout.size(), 0);
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc_zeroed(layout);
+        if !ptr.is_null() && COUNTING.load(Ordering::Relaxed) {
+            self.grow(layout.size(), 0);
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        if COUNTING.load(Ordering::Relaxed) {
+            CURRENT.fetch_sub(layout.size() as i64, Ordering::Relaxed);
+        }
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = System.realloc(ptr, layout, new_size);
+        if !new_ptr.is_null() && COUNTING.load(Ordering::Relaxed) {
+            self.grow(new_size, layout.size());
+        }
+        new_ptr
+    }
+}
+
+/// Install [`CountingAllocator`] as the global allocator of the binary, which
+/// `--profile-mem` needs.
+#[macro_export]
+macro_rules! count_allocations {
+    () => {
+        #[global_allocator]
+        static ALLOCATOR: $crate::CountingAllocator = $crate::CountingAllocator;
+    };
+}
+
+/// The `main` of the binary of a single day: installs [`CountingAllocator`]
+/// and hands the day to [`run`](crate::run).
+#[cfg(feature = "cli")]
+#[macro_export]
+macro_rules! main {
+    ($solution:ty) => {
+        $crate::count_allocations!();
+
+        fn main() {
+            $crate::run::<$solution>();
+        }
+    };
+}
+
+/// Whether the binary installed [`CountingAllocator`].
+pub fn counting_installed() -> bool {
+    let (_, stats) = profile(true, || std::hint::black_box(Box::new(0u8)));
+    stats.is_some_and(|stats| stats.allocations > 0)
+}
+
+/// Run `f`, counting its allocations if `enabled` is set.
+///
+/// Allocations of other threads running at the same time are counted too.
+pub fn profile<T>(enabled: bool, f: impl FnOnce() -> T) -> (T, Option<MemoryStats>) {
+    if !enabled {
+        return (f(), None);
+    }
+    ALLOCATIONS.store(0, Ordering::Relaxed);
+    ALLOCATED.store(0, Ordering::Relaxed);
+    CURRENT.store(0, Ordering::Relaxed);
+    PEAK.store(0, Ordering::Relaxed);
+    COUNTING.store(true, Ordering::SeqCst);
+    let result = f();
+    COUNTING.store(false, Ordering::SeqCst);
+    let stats = MemoryStats {
+        allocations: ALLOCATIONS.load(Ordering::Relaxed),
+        allocated_bytes: ALLOCATED.load(Ordering::Relaxed),
+        peak_bytes: PEAK.load(Ordering::Relaxed).max(0) as u64,
+    };
+    (result, Some(stats))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    crate::count_allocations!();
+
+    #[test]
+    fn test_profile() {
+        // other tests allocate at the same time, so only lower bounds hold
+        let (sum, stats) = profile(true, || {
+            let small: Vec<u64> = (0..10).collect();
+            let large = vec![1u8; 1 << 20];
+            drop(large);
+            let again = vec![1u8; 1 << 20];
+            small.iter().sum::<u64>() + again.len() as u64
+        });
+        let stats = stats.unwrap();
+        assert_eq!(45 + (1 << 20), sum);
+        assert!(stats.allocations >= 3);
+        assert!(stats.allocated_bytes >= 2 << 20);
+        assert!(stats.peak_bytes >= 1 << 20);
+        assert_eq!((1, None), profile(false, || 1));
+        assert!(counting_installed());
+    }
+
+    #[test]
+    fn test_display() {
+        let stats = MemoryStats { allocations: 3, allocated_bytes: 1536, peak_bytes: 1000 };
+        assert_eq!("3 allocations, 1.5 KiB allocated, 1000 B peak", stats.to_string());
+        assert_eq!("2.0 GiB", bytes(2 << 30));
+    }
+}
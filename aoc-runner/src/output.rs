@@ -0,0 +1,232 @@
+use std::io::{self, Write};
+
+use clap::ValueEnum;
+use serde::Serialize;
+
+use crate::{Event, Failure, MemoryStats, PartResult};
+
+/// How results are printed.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, ValueEnum)]
+pub enum Format {
+    /// Human readable, one line per part
+    #[default]
+    Text,
+    /// A single JSON array with one object per part
+    Json,
+    /// A header line followed by one row per part
+    Csv,
+}
+
+/// One part in the machine-readable formats.
+#[derive(Debug, Serialize)]
+struct Record {
+    day: u8,
+    part: u8,
+    answer: Option<String>,
+    elapsed_ns: Option<u128>,
+    input: String,
+    status: &'static str,
+    error: Option<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    trace: Vec<Event>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    memory: Option<MemoryStats>,
+}
+
+const CSV_HEADER: &str = "day,part,answer,elapsed_ns,input,status,error,allocations,allocated_bytes,peak_bytes";
+
+fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+impl Record {
+    fn to_csv(&self) -> String {
+        [
+            self.day.to_string(),
+            self.part.to_string(),
+            csv_field(self.answer.as_deref().unwrap_or_default()),
+            self.elapsed_ns.map(|ns| ns.to_string()).unwrap_or_default(),
+            csv_field(&self.input),
+            self.status.to_string(),
+            csv_field(self.error.as_deref().unwrap_or_default()),
+            self.memory.map(|memory| memory.allocations.to_string()).unwrap_or_default(),
+            self.memory.map(|memory| memory.allocated_bytes.to_string()).unwrap_or_default(),
+            self.memory.map(|memory| memory.peak_bytes.to_string()).unwrap_or_default(),
+        ].join(",")
+    }
+}
+
+/// Prints the results of one or more days in the requested [`Format`].
+///
+/// Text and CSV are written as soon as a day is done, JSON only once
+/// [`Printer::finish`] is called.
+pub struct Printer<W: Write> {
+    format: Format,
+    out: W,
+    json: Vec<Record>,
+}
+
+impl<W: Write> Printer<W> {
+    pub fn new(format: Format, mut out: W) -> io::Result<Printer<W>> {
+        if format == Format::Csv {
+            writeln!(out, "{}", CSV_HEADER)?;
+        }
+        Ok(Printer { format, out, json: Vec::new() })
+    }
+
+    /// Print the outcome of a day.
+    ///
+    /// If the day could not be solved at all, `outcome` holds the reason and
+    /// every part in `parts` is reported as failed.
+    pub fn print_day(&mut self, day: u8, title: &str, input: &str, parts: &[u8],
+                     outcome: &Result<Vec<PartResult>, Failure>) -> io::Result<()> {
+        if self.format == Format::Text {
+            return match outcome {
+                Ok(results) => {
+                    writeln!(self.out, "Day {}: {}", day, title)?;
+                    for result in results {
+                        writeln!(self.out, "{}", result)?;
+                        for event in &result.trace {
+                            writeln!(self.out, "    {}", event)?;
+                        }
+                    }
+                    Ok(())
+                },
+                Err(Failure::Parse { diagnostic, .. }) => {
+                    eprintln!("Day {}: {}", day, diagnostic);
+                    Ok(())
+                },
+                Err(failure) => {
+                    eprintln!("Day {}: {}", day, failure);
+                    Ok(())
+                }
+            };
+        }
+
+        let records: Vec<Record> = match outcome {
+            Ok(results) => results.iter().map(|result| Record {
+                day,
+                part: result.part,
+                answer: result.answer.as_ref().map(|answer| answer.to_string()),
+                elapsed_ns: Some(result.elapsed.as_nanos()),
+                input: input.to_string(),
+                status: match (&result.answer, result.timed_out, result.panicked) {
+                    (_, true, _) => "timeout",
+                    (_, _, true) => "panic",
+                    (Some(_), false, false) => "ok",
+                    (None, false, false) => "no_result",
+                },
+                error: result.panicked.then(|| String::from("the part panicked")),
+                trace: result.trace.clone(),
+                memory: result.memory,
+            }).collect(),
+            Err(failure) => parts.iter().map(|part| Record {
+                day,
+                part: *part,
+                answer: None,
+                elapsed_ns: None,
+                input: input.to_string(),
+                status: "error",
+                error: Some(failure.to_string()),
+                trace: Vec::new(),
+                memory: None,
+            }).collect(),
+        };
+        for record in records {
+            match self.format {
+                Format::Csv => writeln!(self.out, "{}", record.to_csv())?,
+                _ => self.json.push(record),
+            }
+        }
+        Ok(())
+    }
+
+    /// Write out everything that has not been printed yet.
+    pub fn finish(mut self) -> io::Result<()> {
+        if self.format == Format::Json {
+            serde_json::to_writer_pretty(&mut self.out, &self.json)?;
+            writeln!(self.out)?;
+        }
+        self.out.flush()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Answer, ParseError};
+    use std::time::Duration;
+
+    fn results() -> Result<Vec<PartResult>, Failure> {
+        Ok(vec![
+            PartResult { part: 1, answer: Some(Answer::from("a,\"b\"")), elapsed: Duration::from_nanos(42), timed_out: false, panicked: false,
+                         trace: vec![Event { label: "route", detail: String::from("a -> b") }], memory: None },
+            PartResult { part: 2, answer: None, elapsed: Duration::from_nanos(7), timed_out: false, panicked: false, trace: Vec::new(),
+                         memory: Some(MemoryStats { allocations: 2, allocated_bytes: 2048, peak_bytes: 1024 }) },
+        ])
+    }
+
+    fn print(format: Format, outcome: &Result<Vec<PartResult>, Failure>) -> String {
+        let mut out = Vec::new();
+        let mut printer = Printer::new(format, &mut out).unwrap();
+        printer.print_day(3, "Test", "inputs/day03.txt", &[1, 2], outcome).unwrap();
+        printer.finish().unwrap();
+        String::from_utf8(out).unwrap()
+    }
+
+    #[test]
+    fn test_text() {
+        assert_eq!("Day 3: Test\nPart 1: a,\"b\"\t(42ns)\n    route: a -> b\nPart 2: No result\n", print(Format::Text, &results()));
+    }
+
+    #[test]
+    fn test_csv() {
+        let expected = format!("{}\n3,1,\"a,\"\"b\"\"\",42,inputs/day03.txt,ok,,,,\n3,2,,7,inputs/day03.txt,no_result,,2,2048,1024\n", CSV_HEADER);
+        assert_eq!(expected, print(Format::Csv, &results()));
+    }
+
+    #[test]
+    fn test_json() {
+        let output: serde_json::Value = serde_json::from_str(&print(Format::Json, &results())).unwrap();
+        assert_eq!(2, output.as_array().unwrap().len());
+        assert_eq!("a,\"b\"", output[0]["answer"]);
+        assert_eq!(42, output[0]["elapsed_ns"]);
+        assert_eq!("ok", output[0]["status"]);
+        assert_eq!("a -> b", output[0]["trace"][0]["detail"]);
+        assert_eq!(serde_json::Value::Null, output[1]["trace"]);
+        assert_eq!(serde_json::Value::Null, output[1]["answer"]);
+        assert_eq!("no_result", output[1]["status"]);
+        assert_eq!(serde_json::Value::Null, output[0]["memory"]);
+        assert_eq!(1024, output[1]["memory"]["peak_bytes"]);
+    }
+
+    #[test]
+    fn test_panic() {
+        let panicked = Ok(vec![PartResult { part: 1, answer: None, elapsed: Duration::ZERO, timed_out: false, panicked: true, trace: Vec::new(), memory: None }]);
+        assert_eq!("Day 3: Test\nPart 1: PANIC\n", print(Format::Text, &panicked));
+        assert_eq!(format!("{}\n3,1,,0,inputs/day03.txt,panic,the part panicked,,,\n", CSV_HEADER), print(Format::Csv, &panicked));
+        let output: serde_json::Value = serde_json::from_str(&print(Format::Json, &panicked)).unwrap();
+        assert_eq!("panic", output[0]["status"]);
+    }
+
+    #[test]
+    fn test_error() {
+        let output: serde_json::Value = serde_json::from_str(&print(Format::Json, &Err(Failure::Read(String::from("oops"))))).unwrap();
+        assert_eq!(2, output.as_array().unwrap().len());
+        assert_eq!("error", output[1]["status"]);
+        assert_eq!("oops", output[1]["error"]);
+        assert_eq!(format!("{}\n3,1,,,inputs/day03.txt,error,oops,,,\n3,2,,,inputs/day03.txt,error,oops,,,\n", CSV_HEADER),
+                   print(Format::Csv, &Err(Failure::Read(String::from("oops")))));
+    }
+
+    #[test]
+    fn test_parse_failure() {
+        let failure = Failure::parse(ParseError::new(1, 3, "a number", "x"), "day02.txt", "1xx");
+        let output: serde_json::Value = serde_json::from_str(&print(Format::Json, &Err(failure))).unwrap();
+        assert_eq!("line 1, column 3: expected a number, found 'x'", output[0]["error"]);
+    }
+}
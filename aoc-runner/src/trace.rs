@@ -0,0 +1,65 @@
+use std::cell::RefCell;
+use std::fmt::Display;
+
+use serde::Serialize;
+
+/// A step on the way to an answer, e.g. the route day 9 settled on.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Event {
+    /// What the step is about, e.g. "route"
+    pub label: &'static str,
+    pub detail: String,
+}
+
+impl Display for Event {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}: {}", self.label, self.detail)
+    }
+}
+
+thread_local! {
+    // the events of the part running on this thread, None unless explaining
+    static EVENTS: RefCell<Option<Vec<Event>>> = const { RefCell::new(None) };
+}
+
+/// Record how the running part got to its answer.
+///
+/// `detail` is only called when the part runs with `--explain`, otherwise
+/// tracing costs next to nothing.
+pub fn trace<D: Display>(label: &'static str, detail: impl FnOnce() -> D) {
+    EVENTS.with_borrow_mut(|events| {
+        if let Some(events) = events {
+            events.push(Event { label, detail: detail().to_string() });
+        }
+    });
+}
+
+/// Run `f`, collecting the events it traces if `explain` is set.
+pub fn collect<T>(explain: bool, f: impl FnOnce() -> T) -> (T, Vec<Event>) {
+    if !explain {
+        return (f(), Vec::new());
+    }
+    let outer = EVENTS.replace(Some(Vec::new()));
+    let result = f();
+    let events = EVENTS.replace(outer).unwrap_or_default();
+    (result, events)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_collect() {
+        let (answer, events) = collect(true, || {
+            trace("route", || "London -> Dublin");
+            42
+        });
+        assert_eq!(42, answer);
+        assert_eq!(vec![Event { label: "route", detail: String::from("London -> Dublin") }], events);
+        assert_eq!("route: London -> Dublin", events[0].to_string());
+
+        let (_, events) = collect(false, || trace("route", || -> String { panic!("not explaining") }));
+        assert!(events.is_empty());
+    }
+}
@@ -0,0 +1,230 @@
+use std::collections::HashSet;
+use std::fs;
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::sync::{mpsc, Arc};
+use std::time::Duration;
+
+use clap::Args;
+use notify::{RecursiveMode, Watcher};
+
+use crate::{execute, fixture_dir, load_fixtures, DayEntry, Failure, InputSource, Instruments, Limits, PartResult};
+
+/// Whether to keep solving as the inputs change.
+#[derive(Debug, Clone, Default, Args)]
+pub struct WatchOptions {
+    /// Solve again whenever the input file changes, comparing the answers
+    /// and timings to the previous run
+    #[arg(long, conflicts_with = "format")]
+    pub watch: bool,
+
+    /// With --watch, also check the fixtures of the day in this directory
+    /// whenever the input or one of them changes
+    #[arg(long, requires = "watch")]
+    pub fixtures: Option<PathBuf>,
+}
+
+// editors tend to write a file in several steps, wait for them to finish
+const SETTLE: Duration = Duration::from_millis(100);
+
+/// A day being watched, with the results of its last run.
+struct Watched {
+    entry: DayEntry,
+    input: PathBuf,
+    previous: Vec<PartResult>,
+}
+
+fn absolute(path: &Path) -> io::Result<PathBuf> {
+    let parent = match path.parent() {
+        Some(parent) if !parent.as_os_str().is_empty() => parent,
+        _ => Path::new("."),
+    };
+    Ok(fs::canonicalize(parent)?.join(path.file_name().unwrap_or_default()))
+}
+
+/// One part compared to its previous run.
+fn describe(result: &PartResult, previous: Option<&PartResult>) -> String {
+    let Some(previous) = previous.filter(|_| result.failure().is_none()) else {
+        return result.to_string();
+    };
+    let answer = match (&result.answer, &previous.answer, previous.failure()) {
+        (Some(answer), _, Some(failure)) => format!("{} (was {})", answer, failure),
+        (None, _, Some(failure)) => format!("No result (was {})", failure),
+        (Some(answer), Some(before), None) if answer == before => format!("{} (unchanged)", answer),
+        (Some(answer), Some(before), None) => format!("{} (was {})", answer, before),
+        (Some(answer), None, None) => format!("{} (was no result)", answer),
+        (None, Some(before), None) => format!("No result (was {})", before),
+        (None, None, None) => String::from("No result (unchanged)"),
+    };
+    format!("Part {}: {}\t({}, was {:?})", result.part, answer, result.cost(), previous.elapsed)
+}
+
+/// Solve the day once more and print how the results changed.
+fn rerun<W: Write>(watched: &mut Watched, part: Option<u8>, fixtures: Option<&Path>, limits: &Limits, instruments: &Instruments,
+                   out: &mut W) -> io::Result<()> {
+    let entry = &watched.entry;
+    writeln!(out, "Day {}: {}", entry.day, entry.title)?;
+    match entry.prepare(&InputSource::File(watched.input.clone())) {
+        Ok(prepared) => {
+            let tasks = (1..=2).filter(|p| part.is_none_or(|part| part == *p)).map(|part| (Arc::clone(&prepared), part)).collect();
+            let mut results = Vec::new();
+            execute(tasks, limits, instruments, |_, result| results.push(result));
+            results.sort_by_key(|result| result.part);
+            for result in &results {
+                let previous = watched.previous.iter().find(|previous| previous.part == result.part);
+                writeln!(out, "{}", describe(result, previous))?;
+                for event in &result.trace {
+                    writeln!(out, "    {}", event)?;
+                }
+            }
+            watched.previous = results;
+        },
+        Err(Failure::Parse { diagnostic, .. }) => writeln!(out, "{}", diagnostic)?,
+        Err(failure) => writeln!(out, "{}", failure)?,
+    }
+    if let Some(root) = fixtures {
+        // a fixture being edited is often broken for a moment, keep watching
+        match load_fixtures(root, entry.day) {
+            Ok(fixtures) => {
+                let mismatches: Vec<String> = fixtures.iter().flat_map(|fixture| fixture.check(entry, limits)).collect();
+                writeln!(out, "Fixtures: {} checked, {} mismatches", fixtures.len(), mismatches.len())?;
+                for mismatch in mismatches {
+                    writeln!(out, "    {}", mismatch)?;
+                }
+            },
+            Err(err) => writeln!(out, "Fixtures: could not load them: {}", err)?,
+        }
+    }
+    out.flush()
+}
+
+/// Solve `days` on their inputs, then again every time an input (or with
+/// `fixtures`, a fixture of the day) changes. The parts run within `limits`
+/// and record what `instruments` asks for, like with [`run_days`](crate::run_days).
+/// Runs until watching fails.
+pub fn watch<W: Write>(days: &[(DayEntry, PathBuf)], part: Option<u8>, fixtures: Option<&Path>, limits: &Limits,
+                       instruments: &Instruments, out: &mut W) -> io::Result<()> {
+    let (sender, receiver) = mpsc::channel();
+    let mut watcher = notify::recommended_watcher(sender).map_err(io::Error::other)?;
+    // watch the directories, editors often replace a file instead of writing it
+    let mut watched = Vec::new();
+    let mut directories = HashSet::new();
+    for (entry, input) in days {
+        let input = absolute(input)?;
+        if directories.insert(input.parent().unwrap().to_path_buf()) {
+            watcher.watch(input.parent().unwrap(), RecursiveMode::NonRecursive).map_err(io::Error::other)?;
+        }
+        watched.push(Watched { entry: *entry, input, previous: Vec::new() });
+    }
+    let fixtures = fixtures.map(fs::canonicalize).transpose()?;
+    if let Some(root) = &fixtures {
+        watcher.watch(root, RecursiveMode::Recursive).map_err(io::Error::other)?;
+    }
+
+    for day in watched.iter_mut() {
+        rerun(day, part, fixtures.as_deref(), limits, instruments, out)?;
+    }
+    loop {
+        let mut events = vec![receiver.recv().map_err(io::Error::other)?];
+        events.extend(std::iter::from_fn(|| receiver.recv_timeout(SETTLE).ok()));
+        let mut changed = Vec::new();
+        for event in events {
+            let event = event.map_err(io::Error::other)?;
+            // reading the files ourselves must not start another run
+            if !event.kind.is_access() {
+                changed.extend(event.paths);
+            }
+        }
+        for day in watched.iter_mut() {
+            let fixture_dir = fixtures.as_ref().map(|root| fixture_dir(root, day.entry.day));
+            if changed.iter().any(|path| *path == day.input || fixture_dir.as_ref().is_some_and(|dir| path.starts_with(dir))) {
+                writeln!(out)?;
+                rerun(day, part, fixtures.as_deref(), limits, instruments, out)?;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{lines, Answer, ParseError, Solution};
+
+    struct Sum;
+
+    impl Solution for Sum {
+        const DAY: u8 = 1;
+        const TITLE: &'static str = "Sum";
+        type Input = Vec<i64>;
+
+        fn parse(input: &str) -> Result<Vec<i64>, ParseError> {
+            lines(input).map(|line| line.parse(line.text, "a number")).collect()
+        }
+
+        fn part1(input: &Vec<i64>) -> Option<Answer> {
+            Some(input.iter().sum::<i64>().into())
+        }
+
+        fn part2(input: &Vec<i64>) -> Option<Answer> {
+            input.iter().max().map(|max| Answer::from(*max))
+        }
+    }
+
+    #[test]
+    fn test_rerun() {
+        let input = std::env::temp_dir().join(format!("aoc-runner-watch-{}.txt", std::process::id()));
+        let mut watched = Watched { entry: DayEntry::new::<Sum>(), input: input.clone(), previous: Vec::new() };
+        let mut run = |contents: &str| {
+            fs::write(&input, contents).unwrap();
+            let mut out = Vec::new();
+            rerun(&mut watched, None, None, &Limits::default(), &Instruments::default(), &mut out).unwrap();
+            // the timings differ from run to run
+            String::from_utf8(out).unwrap().lines().map(|line| line.split('\t').next().unwrap().to_string()).collect::<Vec<_>>()
+        };
+        assert_eq!(vec!["Day 1: Sum", "Part 1: 3", "Part 2: 2"], run("1\n2"));
+        assert_eq!(vec!["Day 1: Sum", "Part 1: 6 (was 3)", "Part 2: 3 (was 2)"], run("1\n2\n3"));
+        assert_eq!(vec!["Day 1: Sum", "Part 1: 6 (unchanged)", "Part 2: 3 (unchanged)"], run("3\n2\n1"));
+        assert_eq!("Day 1: Sum", run("1\nx")[0]);
+        assert!(run("1\nx").iter().any(|line| line.contains("expected a number, found 'x'")));
+        assert_eq!(vec!["Day 1: Sum", "Part 1: 0 (was 6)", "Part 2: No result (was 3)"], run(""));
+        fs::remove_file(&input).unwrap();
+    }
+
+    struct Fragile;
+
+    impl Solution for Fragile {
+        const DAY: u8 = 2;
+        const TITLE: &'static str = "Fragile";
+        type Input = String;
+
+        fn parse(input: &str) -> Result<String, ParseError> {
+            Ok(input.trim().to_string())
+        }
+
+        fn part1(input: &String) -> Option<Answer> {
+            assert_ne!("boom", input);
+            Some(input.len().into())
+        }
+
+        fn part2(_: &String) -> Option<Answer> {
+            None
+        }
+    }
+
+    #[test]
+    fn test_rerun_survives() {
+        let root = std::env::temp_dir().join(format!("aoc-runner-watch-fixtures-{}", std::process::id()));
+        fs::create_dir_all(fixture_dir(&root, 2)).unwrap();
+        // an input without answers
+        fs::write(fixture_dir(&root, 2).join("broken.txt"), "abc").unwrap();
+        let input = root.join("day02.txt");
+        fs::write(&input, "boom").unwrap();
+        let mut watched = Watched { entry: DayEntry::new::<Fragile>(), input, previous: Vec::new() };
+        let mut out = Vec::new();
+        rerun(&mut watched, Some(1), Some(&root), &Limits::default(), &Instruments::default(), &mut out).unwrap();
+        let out = String::from_utf8(out).unwrap();
+        fs::remove_dir_all(&root).unwrap();
+        assert!(out.contains("Part 1: PANIC"), "{}", out);
+        assert!(out.contains("Fixtures: could not load them: "), "{}", out);
+    }
+}
@@ -0,0 +1,315 @@
+//! State-space search for the puzzles that explore moves, spells or
+//! replacements until they reach a goal.
+//!
+//! A problem implements [`SearchState`] and picks a [`Strategy`], [`search`]
+//! returns the path to the goal together with some statistics.
+
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap, HashSet, VecDeque};
+use std::fmt::Debug;
+use std::hash::Hash;
+use std::ops::Add;
+
+/// A state of a search problem.
+pub trait SearchState: Clone {
+    /// Identifies states that are the same for the search, so they are only
+    /// explored once.
+    type Key: Hash + Eq;
+    /// Cost of a path, [`Default`] being the cost of the empty path.
+    type Cost: Copy + Ord + Add<Output = Self::Cost> + Default + Debug;
+
+    /// The states reachable in one step, with the cost of that step.
+    fn successors(&self) -> Vec<(Self, Self::Cost)>;
+
+    fn is_goal(&self) -> bool;
+
+    fn key(&self) -> Self::Key;
+
+    /// Estimate of the cost still needed to reach a goal. A* and IDA* only
+    /// find the cheapest path if it never overestimates.
+    fn heuristic(&self) -> Self::Cost {
+        Self::Cost::default()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Strategy {
+    /// Fewest steps, ignoring the costs
+    Bfs,
+    /// Any path, trying successors in the order they are returned
+    Dfs,
+    /// Cheapest path
+    Dijkstra,
+    /// Cheapest path, guided by the heuristic
+    AStar,
+    /// Cheapest path like A*, but using memory only for the current path
+    IdaStar,
+}
+
+/// How much work a search did.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Stats {
+    /// States whose successors were generated
+    pub expanded: usize,
+    /// Largest number of states waiting to be expanded at once (the
+    /// deepest path for IDA*)
+    pub max_frontier: usize,
+}
+
+/// The path from the start to a goal, both included.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Found<S: SearchState> {
+    pub path: Vec<S>,
+    pub cost: S::Cost,
+}
+
+impl<S: SearchState> Found<S> {
+    /// Number of steps taken.
+    pub fn steps(&self) -> usize {
+        self.path.len() - 1
+    }
+
+    pub fn goal(&self) -> &S {
+        self.path.last().unwrap()
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Outcome<S: SearchState> {
+    /// `None` if no goal is reachable
+    pub found: Option<Found<S>>,
+    pub stats: Stats,
+}
+
+/// Search a goal reachable from `start`.
+pub fn search<S: SearchState>(start: S, strategy: Strategy) -> Outcome<S> {
+    match strategy {
+        Strategy::Bfs => breadth_first(start),
+        Strategy::Dfs => depth_first(start),
+        Strategy::Dijkstra => best_first(start, false),
+        Strategy::AStar => best_first(start, true),
+        Strategy::IdaStar => iterative_deepening(start),
+    }
+}
+
+// every state ever generated, pointing back at the state it was reached from
+struct Tree<S: SearchState> {
+    nodes: Vec<(S, Option<usize>, S::Cost)>,
+}
+
+impl<S: SearchState> Tree<S> {
+    fn new(start: S) -> Tree<S> {
+        Tree { nodes: vec![(start, None, S::Cost::default())] }
+    }
+
+    fn add(&mut self, state: S, parent: usize, cost: S::Cost) -> usize {
+        self.nodes.push((state, Some(parent), cost));
+        self.nodes.len() - 1
+    }
+
+    fn found(&self, mut index: usize) -> Found<S> {
+        let cost = self.nodes[index].2;
+        let mut path = vec![self.nodes[index].0.clone()];
+        while let Some(parent) = self.nodes[index].1 {
+            index = parent;
+            path.push(self.nodes[index].0.clone());
+        }
+        path.reverse();
+        Found { path, cost }
+    }
+}
+
+fn breadth_first<S: SearchState>(start: S) -> Outcome<S> {
+    let mut stats = Stats::default();
+    let mut seen = HashSet::from([start.key()]);
+    let mut tree = Tree::new(start);
+    let mut queue = VecDeque::from([0]);
+    while let Some(index) = queue.pop_front() {
+        let (state, _, cost) = &tree.nodes[index];
+        if state.is_goal() {
+            return Outcome { found: Some(tree.found(index)), stats };
+        }
+        stats.expanded += 1;
+        let cost = *cost;
+        for (next, step) in state.successors() {
+            if seen.insert(next.key()) {
+                queue.push_back(tree.add(next, index, cost + step));
+            }
+        }
+        stats.max_frontier = stats.max_frontier.max(queue.len());
+    }
+    Outcome { found: None, stats }
+}
+
+fn depth_first<S: SearchState>(start: S) -> Outcome<S> {
+    let mut stats = Stats::default();
+    let mut seen = HashSet::new();
+    let mut tree = Tree::new(start);
+    let mut stack = vec![0];
+    while let Some(index) = stack.pop() {
+        let (state, _, cost) = &tree.nodes[index];
+        if !seen.insert(state.key()) {
+            continue;
+        }
+        if state.is_goal() {
+            return Outcome { found: Some(tree.found(index)), stats };
+        }
+        stats.expanded += 1;
+        let cost = *cost;
+        // the first successor has to end up on top
+        for (next, step) in state.successors().into_iter().rev() {
+            if !seen.contains(&next.key()) {
+                stack.push(tree.add(next, index, cost + step));
+            }
+        }
+        stats.max_frontier = stats.max_frontier.max(stack.len());
+    }
+    Outcome { found: None, stats }
+}
+
+fn best_first<S: SearchState>(start: S, guided: bool) -> Outcome<S> {
+    let priority = |state: &S, cost: S::Cost| if guided { cost + state.heuristic() } else { cost };
+    let mut stats = Stats::default();
+    let mut best = HashMap::from([(start.key(), S::Cost::default())]);
+    let mut heap = BinaryHeap::from([Reverse((priority(&start, S::Cost::default()), 0))]);
+    let mut tree = Tree::new(start);
+    while let Some(Reverse((_, index))) = heap.pop() {
+        let (state, _, cost) = &tree.nodes[index];
+        // a cheaper way to this state was found after queueing it
+        if best.get(&state.key()).is_some_and(|best| best < cost) {
+            continue;
+        }
+        if state.is_goal() {
+            return Outcome { found: Some(tree.found(index)), stats };
+        }
+        stats.expanded += 1;
+        let cost = *cost;
+        for (next, step) in state.successors() {
+            let cost = cost + step;
+            let key = next.key();
+            if best.get(&key).is_none_or(|best| cost < *best) {
+                best.insert(key, cost);
+                let priority = priority(&next, cost);
+                heap.push(Reverse((priority, tree.add(next, index, cost))));
+            }
+        }
+        stats.max_frontier = stats.max_frontier.max(heap.len());
+    }
+    Outcome { found: None, stats }
+}
+
+// Ok with the cost of the goal, or Err with the smallest estimate that
+// exceeded the bound
+fn deepen<S: SearchState>(path: &mut Vec<S>, on_path: &mut HashSet<S::Key>, cost: S::Cost,
+                          bound: S::Cost, stats: &mut Stats) -> Result<S::Cost, Option<S::Cost>> {
+    let state = path.last().unwrap().clone();
+    let estimate = cost + state.heuristic();
+    if estimate > bound {
+        return Err(Some(estimate));
+    }
+    if state.is_goal() {
+        return Ok(cost);
+    }
+    stats.expanded += 1;
+    stats.max_frontier = stats.max_frontier.max(path.len());
+    let mut next_bound = None;
+    for (next, step) in state.successors() {
+        let key = next.key();
+        if on_path.contains(&key) {
+            continue;
+        }
+        path.push(next);
+        on_path.insert(key);
+        match deepen(path, on_path, cost + step, bound, stats) {
+            Ok(cost) => return Ok(cost),
+            Err(None) => (),
+            Err(Some(exceeded)) => next_bound = Some(next_bound.map_or(exceeded, |bound: S::Cost| bound.min(exceeded))),
+        }
+        on_path.remove(&path.pop().unwrap().key());
+    }
+    Err(next_bound)
+}
+
+fn iterative_deepening<S: SearchState>(start: S) -> Outcome<S> {
+    let mut stats = Stats::default();
+    let mut bound = start.heuristic();
+    loop {
+        let mut path = vec![start.clone()];
+        let mut on_path = HashSet::from([start.key()]);
+        match deepen(&mut path, &mut on_path, S::Cost::default(), bound, &mut stats) {
+            Ok(cost) => return Outcome { found: Some(Found { path, cost }), stats },
+            Err(Some(exceeded)) => bound = exceeded,
+            Err(None) => return Outcome { found: None, stats },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // get from 1 to 10 by adding 1 (cost 1) or doubling (cost 3)
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    struct Number(u32);
+
+    impl SearchState for Number {
+        type Key = u32;
+        type Cost = u32;
+
+        fn successors(&self) -> Vec<(Number, u32)> {
+            [(Number(self.0 + 1), 1), (Number(self.0 * 2), 3)].into_iter().filter(|(n, _)| n.0 <= 10).collect()
+        }
+
+        fn is_goal(&self) -> bool {
+            self.0 == 10
+        }
+
+        fn key(&self) -> u32 {
+            self.0
+        }
+
+        fn heuristic(&self) -> u32 {
+            if self.0 == 10 { 0 } else { 1 }
+        }
+    }
+
+    fn path(found: &Found<Number>) -> Vec<u32> {
+        found.path.iter().map(|n| n.0).collect()
+    }
+
+    #[test]
+    fn test_fewest_steps() {
+        let outcome = search(Number(1), Strategy::Bfs);
+        let found = outcome.found.unwrap();
+        assert_eq!(vec![1, 2, 4, 5, 10], path(&found));
+        assert_eq!(4, found.steps());
+        assert_eq!(8, found.cost);
+        assert!(outcome.stats.expanded > 0);
+    }
+
+    #[test]
+    fn test_cheapest() {
+        for strategy in [Strategy::Dijkstra, Strategy::AStar, Strategy::IdaStar] {
+            let outcome = search(Number(1), strategy);
+            let found = outcome.found.unwrap();
+            assert_eq!(vec![1, 2, 3, 4, 5, 10], path(&found), "{:?}", strategy);
+            assert_eq!(7, found.cost);
+            assert!(outcome.stats.max_frontier > 0);
+        }
+    }
+
+    #[test]
+    fn test_depth_first() {
+        let found = search(Number(1), Strategy::Dfs).found.unwrap();
+        // always adds 1 first
+        assert_eq!((1..=10).collect::<Vec<_>>(), path(&found));
+        assert_eq!(&Number(10), found.goal());
+    }
+
+    #[test]
+    fn test_unreachable() {
+        for strategy in [Strategy::Bfs, Strategy::Dfs, Strategy::Dijkstra, Strategy::AStar, Strategy::IdaStar] {
+            assert_eq!(None, search(Number(11), strategy).found, "{:?}", strategy);
+        }
+    }
+}
@@ -0,0 +1,246 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::mpsc;
+use std::thread;
+use std::time::Duration;
+
+use aoc_runner::{parse_timeout, DayEntry, Limits, ParseError, Stats};
+use clap::Args;
+use serde::{Deserialize, Serialize};
+
+use crate::{read_input, report_parse_error, select_days, Selection};
+
+#[derive(Debug, Args)]
+pub struct BenchArgs {
+    #[command(flatten)]
+    selection: Selection,
+
+    /// Number of timed runs per part
+    #[arg(long, default_value_t = 10, value_parser = clap::value_parser!(u64).range(1..))]
+    runs: u64,
+
+    /// Number of untimed runs per part before measuring
+    #[arg(long, default_value_t = 1)]
+    warmup: u64,
+
+    /// File holding the baseline timings
+    #[arg(long, default_value = "bench.json")]
+    baseline: PathBuf,
+
+    /// Slowdown of the median (in percent) that counts as a regression
+    #[arg(long, default_value_t = 10.0)]
+    threshold: f64,
+
+    /// Store the results as the new baseline (done anyway for parts without one)
+    #[arg(long)]
+    save: bool,
+
+    /// Stop measuring a part and report it as TIMEOUT once a single run takes
+    /// longer than this many seconds (parts are measured one at a time, so
+    /// there is no --jobs)
+    #[arg(long, value_parser = parse_timeout)]
+    timeout: Option<Duration>,
+}
+
+/// Timings of a part as stored in the baseline file.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct BaselineEntry {
+    /// hash of the input the timings were taken with
+    input: String,
+    runs: usize,
+    min_ns: u64,
+    median_ns: u64,
+    p95_ns: u64,
+    stddev_ns: u64,
+}
+
+impl BaselineEntry {
+    fn new(input: &str, stats: &Stats) -> BaselineEntry {
+        BaselineEntry {
+            input: input.to_string(),
+            runs: stats.runs,
+            min_ns: stats.min.as_nanos() as u64,
+            median_ns: stats.median.as_nanos() as u64,
+            p95_ns: stats.p95.as_nanos() as u64,
+            stddev_ns: stats.stddev.as_nanos() as u64,
+        }
+    }
+}
+
+/// Baseline timings, keyed by day and part.
+type Baseline = BTreeMap<String, BTreeMap<String, BaselineEntry>>;
+
+fn load_baseline(path: &Path) -> io::Result<Baseline> {
+    match fs::read_to_string(path) {
+        Ok(contents) => serde_json::from_str(&contents)
+            .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err)),
+        Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(Baseline::new()),
+        Err(err) => Err(err),
+    }
+}
+
+fn save_baseline(path: &Path, baseline: &Baseline) -> io::Result<()> {
+    let contents = serde_json::to_string_pretty(baseline)
+        .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))?;
+    fs::write(path, contents + "\n")
+}
+
+/// Relative change of the median compared to the baseline, in percent.
+fn change(baseline: &BaselineEntry, stats: &Stats) -> f64 {
+    let before = baseline.median_ns.max(1) as f64;
+    (stats.median.as_nanos() as f64 - before) / before * 100.0
+}
+
+/// Measure `part` in its own thread, so it can be abandoned if a run never
+/// finishes. `None` if the part ran out of time.
+fn measure_within(entry: DayEntry, contents: &str, part: u8, warmup: usize, runs: usize,
+                  timeout: Option<Duration>) -> Option<Result<Vec<Duration>, ParseError>> {
+    let (sender, receiver) = mpsc::channel();
+    let contents = contents.to_string();
+    thread::spawn(move || {
+        // nobody is listening anymore if the runs took too long
+        let _ = sender.send(entry.measure(&contents, part, warmup, runs, timeout));
+    });
+    let measured = match timeout {
+        // every run but the last one stays within the timeout
+        Some(timeout) => receiver.recv_timeout(timeout * (warmup + runs + 1) as u32).ok()?,
+        None => receiver.recv().ok()?,
+    };
+    match measured {
+        Ok(samples) if samples.len() < runs => None,
+        measured => Some(measured),
+    }
+}
+
+/// Benchmark the selected days and compare them to the baseline.
+///
+/// Returns false if a part regressed, timed out or panicked, or an input was
+/// missing or malformed.
+pub fn bench(args: &BenchArgs) -> bool {
+    let mut baseline = match load_baseline(&args.baseline) {
+        Ok(baseline) => baseline,
+        Err(err) => {
+            eprintln!("Could not load {}: {}", args.baseline.display(), err);
+            return false;
+        }
+    };
+
+    let mut success = true;
+    let mut changed = false;
+    for entry in select_days(&args.selection.days) {
+        let Some(contents) = read_input(&args.selection.inputs, entry.day) else {
+            success = false;
+            continue;
+        };
+        let hash = aoc_runner::input_hash(&contents);
+        let parts = match args.selection.part {
+            Some(part) => part..=part,
+            None => 1..=2,
+        };
+        let limits = Limits { jobs: None, timeout: args.timeout };
+        for part in parts {
+            // one run within the limits first, so a hanging or panicking part is not timed
+            let check = match entry.solve_within(&contents, Some(part), &limits) {
+                Ok(results) => results.into_iter().next().and_then(|result| result.failure()),
+                Err(error) => {
+                    report_parse_error(&args.selection.inputs, entry.day, &contents, error);
+                    success = false;
+                    break;
+                }
+            };
+            if let Some(failure) = check {
+                println!("Day {:2} Part {}: {}", entry.day, part, failure);
+                success = false;
+                continue;
+            }
+            let samples = match measure_within(*entry, &contents, part, args.warmup as usize, args.runs as usize, args.timeout) {
+                Some(Ok(samples)) => samples,
+                None => {
+                    println!("Day {:2} Part {}: TIMEOUT", entry.day, part);
+                    success = false;
+                    continue;
+                },
+                Some(Err(error)) => {
+                    report_parse_error(&args.selection.inputs, entry.day, &contents, error);
+                    success = false;
+                    break;
+                }
+            };
+            let stats = Stats::from_samples(&samples).unwrap();
+            print!("Day {:2} Part {}: min {:>10.2?}  median {:>10.2?}  p95 {:>10.2?}  stddev {:>10.2?}",
+                   entry.day, part, stats.min, stats.median, stats.p95, stats.stddev);
+
+            let day_baseline = baseline.entry(format!("day{:02}", entry.day)).or_default();
+            let part_key = format!("part{}", part);
+            match day_baseline.get(&part_key).filter(|old| old.input == hash) {
+                Some(old) => {
+                    let change = change(old, &stats);
+                    print!("  ({:+.1}% vs {:.2?})", change, Duration::from_nanos(old.median_ns));
+                    if change > args.threshold {
+                        print!("  REGRESSION");
+                        success = false;
+                    }
+                    if args.save {
+                        day_baseline.insert(part_key, BaselineEntry::new(&hash, &stats));
+                        changed = true;
+                    }
+                },
+                None => {
+                    print!("  (new baseline)");
+                    day_baseline.insert(part_key, BaselineEntry::new(&hash, &stats));
+                    changed = true;
+                }
+            }
+            println!();
+        }
+    }
+
+    if changed {
+        if let Err(err) = save_baseline(&args.baseline, &baseline) {
+            eprintln!("Could not write {}: {}", args.baseline.display(), err);
+            return false;
+        }
+    }
+    success
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn stats(median_ms: u64) -> Stats {
+        let median = Duration::from_millis(median_ms);
+        Stats { runs: 1, min: median, median, p95: median, stddev: Duration::ZERO }
+    }
+
+    #[test]
+    fn test_change() {
+        let baseline = BaselineEntry::new("abc", &stats(10));
+        assert_eq!(0.0, change(&baseline, &stats(10)));
+        assert_eq!(50.0, change(&baseline, &stats(15)));
+        assert_eq!(-50.0, change(&baseline, &stats(5)));
+    }
+
+    #[test]
+    fn test_measure_within() {
+        let presents = aoc2015::day(2).unwrap();
+        assert_eq!(3, measure_within(*presents, "2x3x4", 1, 1, 3, Some(Duration::from_secs(10))).unwrap().unwrap().len());
+        // mining a coin takes far longer than a microsecond
+        let coins = aoc2015::day(4).unwrap();
+        assert!(measure_within(*coins, "abcdef", 1, 0, 3, Some(Duration::from_micros(1))).is_none());
+    }
+
+    #[test]
+    fn test_baseline_roundtrip() {
+        let path = std::env::temp_dir().join(format!("aoc2015-bench-{}.json", std::process::id()));
+        let mut baseline = Baseline::new();
+        baseline.entry(String::from("day01")).or_default()
+                .insert(String::from("part1"), BaselineEntry::new("abc", &stats(3)));
+        save_baseline(&path, &baseline).unwrap();
+        let loaded = load_baseline(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+        assert_eq!(baseline, loaded);
+    }
+}
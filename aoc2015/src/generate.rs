@@ -0,0 +1,465 @@
+//! Random puzzle inputs for stress testing and benchmarking.
+//!
+//! Every day has a [`Generator`] producing a valid input from a seed and a
+//! size, e.g. the number of gates of the day 7 circuit or the number of
+//! cities of day 9. The same seed and size always give the same input.
+
+use std::fmt::Write;
+
+use rand::rngs::StdRng;
+use rand::seq::{IndexedRandom, SliceRandom};
+use rand::{RngExt, SeedableRng};
+use serde_json::{Map, Value};
+
+/// Makes random inputs for one day.
+#[derive(Clone, Copy)]
+pub struct Generator {
+    pub day: u8,
+    /// What the size counts, e.g. "gates"
+    pub unit: &'static str,
+    /// Size close to the one of the real puzzle input
+    pub default_size: usize,
+    /// Largest size the day accepts and solves in reasonable time and memory
+    pub max_size: usize,
+    generate: fn(&mut StdRng, usize) -> String,
+}
+
+impl Generator {
+    const fn new(day: u8, unit: &'static str, default_size: usize, max_size: usize, generate: fn(&mut StdRng, usize) -> String) -> Generator {
+        Generator { day, unit, default_size, max_size, generate }
+    }
+
+    /// A random input of `size` (see [`Generator::unit`]) made from `seed`,
+    /// which must not exceed [`Generator::max_size`].
+    pub fn generate(&self, seed: u64, size: usize) -> String {
+        assert!(size <= self.max_size, "day {} takes at most {} {}", self.day, self.max_size, self.unit);
+        let mut rng = StdRng::seed_from_u64(seed);
+        (self.generate)(&mut rng, size)
+    }
+}
+
+/// The generator of every day, in order.
+pub static GENERATORS: [Generator; 25] = [
+    Generator::new(1, "parentheses", 7000, 100_000_000, parentheses),
+    Generator::new(2, "presents", 1000, 1_000_000, presents),
+    Generator::new(3, "moves", 8192, 10_000_000, moves),
+    Generator::new(4, "letters", 8, 1000, secret_key),
+    Generator::new(5, "strings", 1000, 1_000_000, strings),
+    Generator::new(6, "instructions", 300, 10_000, light_instructions),
+    Generator::new(7, "wires", 340, 100_000, circuit),
+    Generator::new(8, "string literals", 300, 1_000_000, string_literals),
+    Generator::new(9, "locations", 8, day09::MAX_LOCATIONS, distances),
+    Generator::new(10, "digits", 10, 100, digits),
+    Generator::new(11, "letters", 8, 64, password),
+    Generator::new(12, "values", 5000, 10_000_000, document),
+    Generator::new(13, "guests", 8, day13::MAX_GUESTS, happiness),
+    Generator::new(14, "reindeer", 9, 256, reindeer),
+    Generator::new(15, "ingredients", 4, 5, ingredients),
+    Generator::new(16, "aunts", 500, 100_000, aunts),
+    Generator::new(17, "containers", 20, 1000, containers),
+    Generator::new(18, "lights per row", 100, 1000, lights),
+    Generator::new(19, "replacements", 25, 100, medicine),
+    Generator::new(20, "presents", 1_000_000, 100_000_000, present_goal),
+    Generator::new(21, "hit points", 100, 1_000_000, equipment_boss),
+    Generator::new(22, "hit points", 55, 100, wizard_boss),
+    Generator::new(23, "instructions", 40, 10_000, program),
+    Generator::new(24, "presents", 24, 48, present_weights),
+    Generator::new(25, "rows and columns", 3000, day25::LIMIT, code_position),
+];
+
+/// The largest size any generator takes.
+pub fn max_size() -> usize {
+    GENERATORS.iter().map(|generator| generator.max_size).max().unwrap()
+}
+
+/// The generator of `day`, if there is one.
+pub fn generator(day: u8) -> Option<&'static Generator> {
+    GENERATORS.iter().find(|generator| generator.day == day)
+}
+
+static LOCATIONS: [&str; 16] = ["Arbre", "Faerun", "Norrath", "Snowdin", "Straylight", "Tambi", "Tristram", "AlphaCentauri",
+                                "Dublin", "London", "Belfast", "Midgard", "Tatooine", "Gallifrey", "Narnia", "Mordor"];
+static PEOPLE: [&str; 16] = ["Alice", "Bob", "Carol", "David", "Eric", "Frank", "George", "Mallory",
+                             "Comet", "Cupid", "Dancer", "Prancer", "Rudolph", "Vixen", "Blitzen", "Dasher"];
+static INGREDIENTS: [&str; 10] = ["Sprinkles", "Butterscotch", "Chocolate", "Candy", "Frosting",
+                                  "Sugar", "Cinnamon", "Vanilla", "Marzipan", "Licorice"];
+static ELEMENTS: [&str; 10] = ["Al", "B", "Ca", "F", "H", "Mg", "N", "O", "P", "Ti"];
+
+/// `count` distinct names picked from `pool`, beyond its size they are
+/// combined like "Alicebob" (only letters, some days do not allow digits).
+fn names(rng: &mut StdRng, pool: &[&str], count: usize) -> Vec<String> {
+    let mut pool = pool.to_vec();
+    pool.shuffle(rng);
+    (0..count).map(|i| match i / pool.len() {
+        0 => pool[i].to_string(),
+        n => format!("{}{}", pool[i % pool.len()], pool[(n - 1) % pool.len()].to_lowercase()),
+    }).collect()
+}
+
+fn letters(rng: &mut StdRng, alphabet: &[u8], count: usize) -> String {
+    (0..count).map(|_| *alphabet.choose(rng).unwrap() as char).collect()
+}
+
+const LOWERCASE: &[u8] = b"abcdefghijklmnopqrstuvwxyz";
+
+// day 1: a line of parentheses
+fn parentheses(rng: &mut StdRng, size: usize) -> String {
+    letters(rng, b"()", size) + "\n"
+}
+
+// day 2: box dimensions like 2x3x4
+fn presents(rng: &mut StdRng, size: usize) -> String {
+    (0..size).map(|_| {
+        let [l, w, h]: [u32; 3] = std::array::from_fn(|_| rng.random_range(1..=30));
+        format!("{}x{}x{}\n", l, w, h)
+    }).collect()
+}
+
+// day 3: a line of moves
+fn moves(rng: &mut StdRng, size: usize) -> String {
+    letters(rng, b"^v<>", size) + "\n"
+}
+
+// day 4: a secret key of lowercase letters
+fn secret_key(rng: &mut StdRng, size: usize) -> String {
+    letters(rng, LOWERCASE, size) + "\n"
+}
+
+// day 5: strings of 16 lowercase letters
+fn strings(rng: &mut StdRng, size: usize) -> String {
+    (0..size).map(|_| letters(rng, LOWERCASE, 16) + "\n").collect()
+}
+
+// day 6: instructions on rectangles within the 1000x1000 grid
+fn light_instructions(rng: &mut StdRng, size: usize) -> String {
+    (0..size).map(|_| {
+        let action = ["turn on", "turn off", "toggle"].choose(rng).unwrap();
+        let (x1, y1) = (rng.random_range(0..1000), rng.random_range(0..1000));
+        let (x2, y2): (u32, u32) = (rng.random_range(x1..1000), rng.random_range(y1..1000));
+        format!("{} {},{} through {},{}\n", action, x1, y1, x2, y2)
+    }).collect()
+}
+
+// the wires of day 7 are named a, b, ..., z, aa, ab, ...
+fn wire_name(mut index: usize) -> String {
+    let mut name = Vec::new();
+    loop {
+        name.push(LOWERCASE[index % 26]);
+        index /= 26;
+        if index == 0 {
+            break;
+        }
+        index -= 1;
+    }
+    name.reverse();
+    String::from_utf8(name).unwrap()
+}
+
+// day 7: a circuit without loops, b gets a signal and a is the last wire
+fn circuit(rng: &mut StdRng, size: usize) -> String {
+    let wires = size.max(2);
+    let name = |wire: usize| if wire + 1 == wires { wire_name(0) } else { wire_name(wire + 1) };
+    let mut gates = vec![format!("{} -> {}", rng.random_range(0..=u16::MAX), name(0))];
+    for wire in 1..wires {
+        // gates only read wires defined before them
+        let left = name(rng.random_range(0..wire));
+        let right = name(rng.random_range(0..wire));
+        let gate = match rng.random_range(0..6) {
+            0 => format!("NOT {}", left),
+            1 if rng.random_bool(0.3) => format!("1 AND {}", right),
+            1 => format!("{} AND {}", left, right),
+            2 => format!("{} OR {}", left, right),
+            3 => format!("{} LSHIFT {}", left, rng.random_range(1..16)),
+            4 => format!("{} RSHIFT {}", left, rng.random_range(1..16)),
+            _ => left,
+        };
+        gates.push(format!("{} -> {}", gate, name(wire)));
+    }
+    gates.shuffle(rng);
+    gates.into_iter().map(|gate| gate + "\n").collect()
+}
+
+// day 8: string literals with escaped backslashes, quotes and characters
+fn string_literals(rng: &mut StdRng, size: usize) -> String {
+    let mut literals = String::new();
+    for _ in 0..size {
+        literals.push('"');
+        for _ in 0..rng.random_range(0..=30) {
+            match rng.random_range(0..10) {
+                0 => literals.push_str("\\\\"),
+                1 => literals.push_str("\\\""),
+                2 => write!(literals, "\\x{:02x}", rng.random_range(0..=u8::MAX)).unwrap(),
+                _ => literals.push(*LOWERCASE.choose(rng).unwrap() as char),
+            }
+        }
+        literals.push_str("\"\n");
+    }
+    literals
+}
+
+// day 9: the distance between every pair of locations
+fn distances(rng: &mut StdRng, size: usize) -> String {
+    let locations = names(rng, &LOCATIONS, size);
+    let mut table = String::new();
+    for (i, from) in locations.iter().enumerate() {
+        for to in &locations[i + 1..] {
+            writeln!(table, "{} to {} = {}", from, to, rng.random_range(10..=150)).unwrap();
+        }
+    }
+    table
+}
+
+// day 10: a line of the digits 1 to 3
+fn digits(rng: &mut StdRng, size: usize) -> String {
+    letters(rng, b"123", size.max(1)) + "\n"
+}
+
+// day 11: a password without the forbidden letters
+fn password(rng: &mut StdRng, size: usize) -> String {
+    // a straight and two pairs need at least 7 letters
+    letters(rng, b"abcdefghjkmnpqrstuvwxyz", size.max(7)) + "\n"
+}
+
+fn word(rng: &mut StdRng) -> String {
+    let length = rng.random_range(1..=6);
+    letters(rng, LOWERCASE, length)
+}
+
+fn json_value(rng: &mut StdRng, budget: &mut usize, depth: usize) -> Value {
+    *budget = budget.saturating_sub(1);
+    if depth > 0 && (*budget == 0 || depth >= 6 || rng.random_bool(0.7)) {
+        return match rng.random_range(0..20) {
+            0 => Value::from("red"),
+            1..=6 => Value::from(word(rng)),
+            _ => Value::from(rng.random_range(-200..=200)),
+        };
+    }
+    // the outermost container takes whatever is left of the budget
+    let count = if depth == 0 { usize::MAX } else { rng.random_range(1..=8) };
+    let mut children = Vec::new();
+    for _ in 0..count {
+        if *budget == 0 {
+            break;
+        }
+        children.push(json_value(rng, budget, depth + 1));
+    }
+    // a red outermost object would leave nothing for part 2
+    if depth == 0 || rng.random_bool(0.5) {
+        return Value::Array(children);
+    }
+    let object: Map<String, Value> = children.into_iter()
+        .map(|child| (word(rng), child))
+        .collect();
+    Value::Object(object)
+}
+
+// day 12: a JSON document of nested arrays and objects, some of them red
+fn document(rng: &mut StdRng, size: usize) -> String {
+    let mut budget = size.max(1);
+    json_value(rng, &mut budget, 0).to_string() + "\n"
+}
+
+// day 13: the happiness of every guest next to every other guest
+fn happiness(rng: &mut StdRng, size: usize) -> String {
+    let guests = names(rng, &PEOPLE, size);
+    let mut table = String::new();
+    for guest in &guests {
+        for neighbor in guests.iter().filter(|neighbor| *neighbor != guest) {
+            let change = if rng.random_bool(0.5) { "gain" } else { "lose" };
+            writeln!(table, "{} would {} {} happiness units by sitting next to {}.", guest, change, rng.random_range(0..=100), neighbor).unwrap();
+        }
+    }
+    table
+}
+
+// day 14: reindeer speeds and stamina
+fn reindeer(rng: &mut StdRng, size: usize) -> String {
+    names(rng, &PEOPLE, size).into_iter().map(|name| {
+        format!("{} can fly {} km/s for {} seconds, but then must rest for {} seconds.\n",
+                name, rng.random_range(5..=30), rng.random_range(2..=20), rng.random_range(20..=180))
+    }).collect()
+}
+
+// day 15: ingredient properties, each ingredient is good at one property
+// so that some cookies score
+fn ingredients(rng: &mut StdRng, size: usize) -> String {
+    names(rng, &INGREDIENTS, size).into_iter().enumerate().map(|(i, name)| {
+        let [capacity, durability, flavor, texture]: [i32; 4] = std::array::from_fn(|property| {
+            if property == i % 4 { rng.random_range(3..=6) } else { rng.random_range(-1..=0) }
+        });
+        format!("{}: capacity {}, durability {}, flavor {}, texture {}, calories {}\n",
+                name, capacity, durability, flavor, texture, rng.random_range(1..=9))
+    }).collect()
+}
+
+// day 16: three remembered facts per aunt, one aunt matches the MFCSAM
+fn aunts(rng: &mut StdRng, size: usize) -> String {
+    let known = day16::known_facts();
+    let mut things: Vec<&String> = known.keys().collect();
+    things.sort();
+    let gifter = rng.random_range(0..size.max(1));
+    (0..size).map(|aunt| {
+        let facts: Vec<String> = things.sample(rng, 3).map(|thing| {
+            let amount = if aunt == gifter { known[*thing] } else { rng.random_range(0..=10) };
+            format!("{}: {}", thing, amount)
+        }).collect();
+        format!("Sue {}: {}\n", aunt + 1, facts.join(", "))
+    }).collect()
+}
+
+// day 17: container sizes
+fn containers(rng: &mut StdRng, size: usize) -> String {
+    (0..size).map(|_| format!("{}\n", rng.random_range(5..=50))).collect()
+}
+
+// day 18: a square grid of lights
+fn lights(rng: &mut StdRng, size: usize) -> String {
+    (0..size.max(1)).map(|_| letters(rng, b"#.", size.max(1)) + "\n").collect()
+}
+
+// day 19: replacements of single elements and a medicine built from e with them
+fn medicine(rng: &mut StdRng, size: usize) -> String {
+    let mut rules: Vec<(&str, Vec<&str>)> = Vec::new();
+    for _ in 0..3 {
+        rules.push(("e", ELEMENTS.sample(rng, 2).copied().collect()));
+    }
+    for element in ELEMENTS {
+        for _ in 0..rng.random_range(1..=3) {
+            let length = rng.random_range(2..=3);
+            rules.push((element, (0..length).map(|_| *ELEMENTS.choose(rng).unwrap()).collect()));
+        }
+    }
+    let mut molecule = vec!["e"];
+    for _ in 0..size.max(1) {
+        let position = rng.random_range(0..molecule.len());
+        let (_, output) = rules.iter().filter(|(input, _)| *input == molecule[position]).collect::<Vec<_>>()
+                               .choose(rng).unwrap();
+        molecule.splice(position..=position, output.iter().copied());
+    }
+    let mut input: String = rules.iter().map(|(input, output)| format!("{} => {}\n", input, output.concat())).collect();
+    input.push('\n');
+    input + &molecule.concat() + "\n"
+}
+
+// day 20: a number of presents up to the size
+fn present_goal(rng: &mut StdRng, size: usize) -> String {
+    format!("{}\n", rng.random_range(size.div_ceil(2).max(1)..=size.max(1)))
+}
+
+// day 21: a boss that can be beaten with the shop's items
+fn equipment_boss(rng: &mut StdRng, size: usize) -> String {
+    format!("Hit Points: {}\nDamage: {}\nArmor: {}\n", size.max(1), rng.random_range(4..=9), rng.random_range(0..=3))
+}
+
+// day 22: a boss for the wizard
+fn wizard_boss(rng: &mut StdRng, size: usize) -> String {
+    format!("Hit Points: {}\nDamage: {}\n", size.max(1), rng.random_range(5..=10))
+}
+
+// instructions bringing register a from `start` to some larger number
+fn setup(rng: &mut StdRng, mut start: u64, count: usize) -> Vec<String> {
+    (0..count).map(|_| {
+        // keep the Collatz sequence of a far from overflowing
+        if start > 0 && start < 10_000_000 && rng.random_bool(0.5) {
+            start *= 3;
+            "tpl a".to_string()
+        } else {
+            start += 1;
+            "inc a".to_string()
+        }
+    }).collect()
+}
+
+// day 23: a program that sets up a differently for both parts and counts the
+// steps of its Collatz sequence in b
+fn program(rng: &mut StdRng, size: usize) -> String {
+    let first = setup(rng, 0, (size / 2).max(1));
+    let second = setup(rng, 1, (size / 2).max(1));
+    let mut program = vec![format!("jio a, +{}", first.len() + 2)];
+    program.extend(first);
+    program.push(format!("jmp +{}", second.len() + 1));
+    program.extend(second);
+    program.extend(["jio a, +8", "inc b", "jie a, +4", "tpl a", "inc a", "jmp +2", "hlf a", "jmp -7"].map(String::from));
+    program.into_iter().map(|instruction| instruction + "\n").collect()
+}
+
+// day 24: distinct weights in pairs of equal sum, 12 pairs each time so they
+// can be split into both 3 and 4 groups
+fn present_weights(rng: &mut StdRng, size: usize) -> String {
+    let pairs = size.div_ceil(24).max(1) * 12;
+    let sum = rng.random_range(2 * pairs + 2..=4 * pairs + 20);
+    let smaller: Vec<usize> = (1..=(sum - 1) / 2).collect();
+    let mut weights: Vec<usize> = smaller.sample(rng, pairs).flat_map(|weight| [*weight, sum - weight]).collect();
+    weights.sort();
+    weights.into_iter().map(|weight| format!("{}\n", weight)).collect()
+}
+
+// day 25: a position in the code grid
+fn code_position(rng: &mut StdRng, size: usize) -> String {
+    format!("To continue, please consult the code grid in the manual.  Enter the code at row {}, column {}.\n",
+            rng.random_range(1..=size.max(1)), rng.random_range(1..=size.max(1)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_runner::InputSource;
+
+    use aoc2015::DAYS;
+
+    #[test]
+    fn test_inputs_parse() {
+        for (generator, entry) in GENERATORS.iter().zip(&DAYS) {
+            assert_eq!(entry.day, generator.day);
+            for seed in 0..3 {
+                let input = generator.generate(seed, generator.default_size);
+                if let Err(failure) = entry.prepare(&InputSource::Inline(input)) {
+                    panic!("day {} with seed {}: {}", generator.day, seed, failure);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_deterministic() {
+        for generator in &GENERATORS {
+            let size = generator.max_size.min(10);
+            assert_eq!(generator.generate(7, size), generator.generate(7, size));
+        }
+        assert_ne!(generator(7).unwrap().generate(1, 10), generator(7).unwrap().generate(2, 10));
+        assert!(generator(26).is_none());
+    }
+
+    #[test]
+    fn test_solvable() {
+        // circuits and programs must not loop, the presents must balance
+        for seed in 0..3 {
+            for day in [7, 23, 24] {
+                let input = generator(day).unwrap().generate(seed, 20);
+                let results = aoc2015::solve(day, &input, None).unwrap().unwrap();
+                assert!(results.iter().all(|result| result.answer.is_some()), "day {} with seed {}", day, seed);
+            }
+        }
+        let input = generator(24).unwrap().generate(3, 24);
+        assert_eq!(24, input.lines().count());
+    }
+
+    #[test]
+    fn test_max_size() {
+        // the largest routing and seating inputs still parse
+        for day in [9, 13] {
+            let generator = generator(day).unwrap();
+            let input = generator.generate(1, generator.max_size);
+            assert!(DAYS[day as usize - 1].prepare(&InputSource::Inline(input)).is_ok(), "day {}", day);
+        }
+        assert_eq!(day25::LIMIT, max_size());
+    }
+
+    #[test]
+    fn test_wire_name() {
+        assert_eq!("a", wire_name(0));
+        assert_eq!("z", wire_name(25));
+        assert_eq!("aa", wire_name(26));
+        assert_eq!("ba", wire_name(52));
+    }
+}
@@ -0,0 +1,87 @@
+use std::fs;
+use std::io::{self, Write};
+use std::ops::RangeInclusive;
+use std::path::PathBuf;
+
+use aoc_runner::input_path;
+use clap::Args;
+
+use crate::generate::{generator, max_size};
+use crate::{parse_days, select_days};
+
+#[derive(Debug, Args)]
+pub struct GenArgs {
+    /// Days to generate inputs for, either single days or ranges like 13-15 (default: all days)
+    #[arg(value_parser = parse_days)]
+    days: Vec<RangeInclusive<u8>>,
+
+    /// Seed of the random inputs, the same seed gives the same inputs
+    #[arg(long, default_value_t = 2015)]
+    seed: u64,
+
+    /// Size of the inputs, e.g. the number of wires for day 7, up to a limit per day [default: close to the real inputs]
+    #[arg(long, value_parser = parse_size)]
+    size: Option<usize>,
+
+    /// Directory to write the inputs to, named dayNN.txt [default: print a single day to stdout]
+    #[arg(long)]
+    out: Option<PathBuf>,
+}
+
+// a size that at least one generator takes
+fn parse_size(text: &str) -> Result<usize, String> {
+    let size: usize = text.parse().map_err(|err| format!("{}", err))?;
+    match size {
+        0 => Err("the size must be positive".to_string()),
+        size if size > max_size() => Err(format!("the size must be at most {}", max_size())),
+        size => Ok(size),
+    }
+}
+
+/// Generate random inputs for the selected days.
+///
+/// Returns false if an input could not be written or `--size` is too large
+/// for one of the days.
+pub fn generate(args: &GenArgs) -> bool {
+    let days = select_days(&args.days);
+    if let Some(size) = args.size {
+        let exceeded: Vec<String> = days.iter().map(|entry| generator(entry.day).unwrap())
+            .filter(|generator| size > generator.max_size)
+            .map(|generator| format!("day {} takes at most {} {}", generator.day, generator.max_size, generator.unit))
+            .collect();
+        if !exceeded.is_empty() {
+            eprintln!("--size {} is too large: {}", size, exceeded.join(", "));
+            return false;
+        }
+    }
+    let Some(out) = &args.out else {
+        let [entry] = days[..] else {
+            eprintln!("Generating several days needs --out");
+            return false;
+        };
+        let generator = generator(entry.day).unwrap();
+        let input = generator.generate(args.seed, args.size.unwrap_or(generator.default_size));
+        return io::stdout().write_all(input.as_bytes())
+            .inspect_err(|err| eprintln!("Could not write the input: {}", err))
+            .is_ok();
+    };
+
+    if let Err(err) = fs::create_dir_all(out) {
+        eprintln!("Could not create {}: {}", out.display(), err);
+        return false;
+    }
+    let mut success = true;
+    for entry in days {
+        let generator = generator(entry.day).unwrap();
+        let size = args.size.unwrap_or(generator.default_size);
+        let path = input_path(out, entry.day);
+        match fs::write(&path, generator.generate(args.seed, size)) {
+            Ok(()) => println!("Wrote {} ({} {})", path.display(), size, generator.unit),
+            Err(err) => {
+                eprintln!("Could not write {}: {}", path.display(), err);
+                success = false;
+            }
+        }
+    }
+    success
+}
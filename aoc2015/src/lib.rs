@@ -0,0 +1,123 @@
+//! All solutions of Advent of Code 2015 in one crate.
+//!
+//! Each day is re-exported with its typed parsers and solvers, e.g.
+//! [`day09::best_route`] or [`day22::find_best_strategy`], and [`solve`] runs any
+//! day on an input held in memory.
+
+use aoc_runner::{DayEntry, ParseError, PartResult};
+
+pub use aoc_graph;
+pub use aoc_grid;
+pub use aoc_math;
+pub use aoc_parse;
+pub use aoc_runner::{Answer, Solution};
+pub use aoc_search;
+pub use day01;
+pub use day02;
+pub use day03;
+pub use day04;
+pub use day05;
+pub use day06;
+pub use day07;
+pub use day08;
+pub use day09;
+pub use day10;
+pub use day11;
+pub use day12;
+pub use day13;
+pub use day14;
+pub use day15;
+pub use day16;
+pub use day17;
+pub use day18;
+pub use day19;
+pub use day20;
+pub use day21;
+pub use day22;
+pub use day23;
+pub use day24;
+pub use day25;
+
+/// Every day, in order.
+pub static DAYS: [DayEntry; 25] = [
+    DayEntry::new::<day01::Day01>(),
+    DayEntry::new::<day02::Day02>(),
+    DayEntry::new::<day03::Day03>(),
+    DayEntry::new::<day04::Day04>(),
+    DayEntry::new::<day05::Day05>(),
+    DayEntry::new::<day06::Day06>(),
+    DayEntry::new::<day07::Day07>(),
+    DayEntry::new::<day08::Day08>(),
+    DayEntry::new::<day09::Day09>(),
+    DayEntry::new::<day10::Day10>(),
+    DayEntry::new::<day11::Day11>(),
+    DayEntry::new::<day12::Day12>(),
+    DayEntry::new::<day13::Day13>(),
+    DayEntry::new::<day14::Day14>(),
+    DayEntry::new::<day15::Day15>(),
+    DayEntry::new::<day16::Day16>(),
+    DayEntry::new::<day17::Day17>(),
+    DayEntry::new::<day18::Day18>(),
+    DayEntry::new::<day19::Day19>(),
+    DayEntry::new::<day20::Day20>(),
+    DayEntry::new::<day21::Day21>(),
+    DayEntry::new::<day22::Day22>(),
+    DayEntry::new::<day23::Day23>(),
+    DayEntry::new::<day24::Day24>(),
+    DayEntry::new::<day25::Day25>(),
+];
+
+/// The entry of `day`, if there is a solution for it.
+pub fn day(day: u8) -> Option<&'static DayEntry> {
+    DAYS.iter().find(|entry| entry.day == day)
+}
+
+/// Solve `day` on `input`, running all parts or only `part`.
+///
+/// Returns `None` if there is no solution for `day`.
+pub fn solve(day: u8, input: &str, part: Option<u8>) -> Option<Result<Vec<PartResult>, ParseError>> {
+    self::day(day).map(|entry| entry.solve(input, part))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_registry_order() {
+        for (i, entry) in DAYS.iter().enumerate() {
+            assert_eq!(i as u8 + 1, entry.day);
+        }
+    }
+
+    #[test]
+    fn test_solve() {
+        let results = solve(2, "2x3x4\n1x1x10", None).unwrap().unwrap();
+        let answers: Vec<Option<Answer>> = results.into_iter().map(|result| result.answer).collect();
+        assert_eq!(vec![Some(Answer::from(101)), Some(Answer::from(48))], answers);
+        assert!(solve(2, "2x3", Some(1)).unwrap().is_err());
+        assert!(solve(26, "", None).is_none());
+    }
+
+    #[test]
+    fn test_fixtures() {
+        let root = std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("../fixtures");
+        let mut mismatches = Vec::new();
+        for entry in &DAYS {
+            let fixtures = aoc_runner::load_fixtures(&root, entry.day).unwrap();
+            assert!(!fixtures.is_empty(), "no fixtures for day {}", entry.day);
+            for fixture in fixtures {
+                mismatches.extend(fixture.check(entry, &aoc_runner::Limits::default()));
+            }
+        }
+        assert!(mismatches.is_empty(), "\n{}", mismatches.join("\n"));
+    }
+
+    #[test]
+    fn test_typed_api() {
+        let graph = day09::parse_distances("London to Dublin = 464\nLondon to Belfast = 518\nDublin to Belfast = 141").unwrap();
+        assert_eq!(605, day09::best_route(&graph, aoc_graph::Goal::Min).unwrap().cost);
+        assert_eq!(Ok(3), day01::final_floor("((("));
+        assert_eq!(2, day03::houses_visited("^v", 1));
+    }
+}
@@ -0,0 +1,180 @@
+mod bench;
+mod generate;
+mod inputs;
+mod report;
+mod scaffold;
+mod tui;
+mod verify;
+
+use std::fs;
+use std::io;
+use std::ops::RangeInclusive;
+use std::path::{Path, PathBuf};
+use std::process;
+
+use aoc2015::DAYS;
+use aoc_runner::{input_path, DayEntry, Format, InputSource, Instruments, Limits, ParseError, Printer, WatchOptions};
+use clap::{Args, Parser, Subcommand};
+
+aoc_runner::count_allocations!();
+
+#[derive(Debug, Parser)]
+#[command(about = "Run the solutions for Advent of Code 2015")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Debug, Subcommand)]
+enum Command {
+    /// Run the solutions of the selected days
+    Run(RunArgs),
+    /// Check the solutions of the selected days against the recorded answers
+    Verify(verify::VerifyArgs),
+    /// Measure the runtime of the selected days and compare it to a baseline
+    Bench(bench::BenchArgs),
+    /// Create the crate for a new day from the template
+    NewDay(scaffold::NewDayArgs),
+    /// Write random puzzle inputs for the selected days
+    Gen(inputs::GenArgs),
+    /// Run the selected days and write an HTML and Markdown report of the results
+    Report(report::ReportArgs),
+    /// Browse the days in the terminal, running them on their real or example inputs
+    Tui(tui::TuiArgs),
+}
+
+/// Which days and parts to execute, and where to find their inputs.
+#[derive(Debug, Args)]
+struct Selection {
+    /// Days to run, either single days or ranges like 13-15 (default: all days)
+    #[arg(value_parser = parse_days)]
+    days: Vec<RangeInclusive<u8>>,
+
+    /// Only run the given part
+    #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: Option<u8>,
+
+    /// Directory containing the puzzle inputs, named dayNN.txt
+    #[arg(long, default_value = "inputs")]
+    inputs: PathBuf,
+}
+
+#[derive(Debug, Args)]
+struct RunArgs {
+    #[command(flatten)]
+    selection: Selection,
+
+    /// Output format
+    #[arg(long, value_enum, default_value_t)]
+    format: Format,
+
+    #[command(flatten)]
+    limits: Limits,
+
+    #[command(flatten)]
+    instruments: Instruments,
+
+    #[command(flatten)]
+    watch: WatchOptions,
+}
+
+fn parse_days(spec: &str) -> Result<RangeInclusive<u8>, String> {
+    let parse_day = |day: &str| -> Result<u8, String> {
+        match day.trim().parse::<u8>() {
+            Ok(day) if (1..=25).contains(&day) => Ok(day),
+            _ => Err(format!("'{}' is not a day between 1 and 25", day)),
+        }
+    };
+    let range = match spec.split_once('-') {
+        Some((first, last)) => parse_day(first)?..=parse_day(last)?,
+        None => parse_day(spec)?..=parse_day(spec)?,
+    };
+    if range.is_empty() {
+        return Err(format!("'{}' is an empty range", spec));
+    }
+    Ok(range)
+}
+
+/// Resolve the day selection to registry entries, in order and without duplicates.
+fn select_days(ranges: &[RangeInclusive<u8>]) -> Vec<&'static DayEntry> {
+    DAYS.iter()
+        .filter(|entry| ranges.is_empty() || ranges.iter().any(|range| range.contains(&entry.day)))
+        .collect()
+}
+
+/// Read the input of `day`, reporting on stderr if it is unavailable.
+fn read_input(inputs: &Path, day: u8) -> Option<String> {
+    let path = input_path(inputs, day);
+    fs::read_to_string(&path)
+        .inspect_err(|err| eprintln!("Day {}: could not read {}: {}", day, path.display(), err))
+        .ok()
+}
+
+/// Report a malformed input of `day` on stderr.
+fn report_parse_error(inputs: &Path, day: u8, contents: &str, error: ParseError) {
+    let path = input_path(inputs, day);
+    eprintln!("Day {}: {}", day, error.diagnostic(&path.display().to_string(), contents));
+}
+
+fn run(args: &RunArgs) -> bool {
+    let selection = &args.selection;
+    if args.watch.watch {
+        let days: Vec<(DayEntry, PathBuf)> = select_days(&selection.days).into_iter()
+            .map(|entry| (*entry, input_path(&selection.inputs, entry.day)))
+            .collect();
+        let err = aoc_runner::watch(&days, selection.part, args.watch.fixtures.as_deref(), &args.limits, &args.instruments,
+                                   &mut io::stdout().lock()).unwrap_err();
+        eprintln!("Stopped watching: {}", err);
+        return false;
+    }
+    let days: Vec<(DayEntry, InputSource)> = select_days(&selection.days).into_iter()
+        .map(|entry| (*entry, InputSource::File(input_path(&selection.inputs, entry.day))))
+        .collect();
+    let outcome = Printer::new(args.format, io::stdout().lock()).and_then(|mut printer| {
+        let success = aoc_runner::run_days(&days, selection.part, &args.limits, &args.instruments, &mut printer)?;
+        printer.finish()?;
+        Ok(success)
+    });
+    outcome.unwrap_or_else(|err| {
+        eprintln!("Could not write results: {}", err);
+        false
+    })
+}
+
+fn main() {
+    let cli = Cli::parse();
+    let success = match &cli.command {
+        Command::Run(args) => run(args),
+        Command::Verify(args) => verify::verify(args),
+        Command::Bench(args) => bench::bench(args),
+        Command::NewDay(args) => scaffold::new_day(args),
+        Command::Gen(args) => inputs::generate(args),
+        Command::Report(args) => report::report(args),
+        Command::Tui(args) => tui::tui(args),
+    };
+    if !success {
+        process::exit(1);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_days() {
+        assert_eq!(Ok(7..=7), parse_days("7"));
+        assert_eq!(Ok(13..=15), parse_days("13-15"));
+        assert!(parse_days("0").is_err());
+        assert!(parse_days("26").is_err());
+        assert!(parse_days("15-13").is_err());
+        assert!(parse_days("x").is_err());
+    }
+
+    #[test]
+    fn test_select_days() {
+        let days: Vec<u8> = select_days(&[13..=15, 7..=7, 9..=9, 14..=14]).iter().map(|e| e.day).collect();
+        assert_eq!(vec![7, 9, 13, 14, 15], days);
+        assert_eq!(25, select_days(&[]).len());
+    }
+}
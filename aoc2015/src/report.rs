@@ -0,0 +1,290 @@
+use std::fmt::Write;
+use std::fs;
+use std::path::PathBuf;
+use std::time::Duration;
+
+use aoc_runner::{input_path, load_fixtures, Answer, AnswerRegistry, Limits};
+use clap::Args;
+
+use crate::verify::{check, Verdict};
+use crate::{select_days, Selection};
+
+#[derive(Debug, Args)]
+pub struct ReportArgs {
+    #[command(flatten)]
+    selection: Selection,
+
+    /// File containing the expected answers
+    #[arg(long, default_value = "answers.toml")]
+    answers: PathBuf,
+
+    /// Directory containing the fixtures of the days
+    #[arg(long, default_value = "fixtures")]
+    fixtures: PathBuf,
+
+    /// Directory to write report.html and report.md to
+    #[arg(long, default_value = "report")]
+    out: PathBuf,
+
+    #[command(flatten)]
+    limits: Limits,
+}
+
+/// A part as it appears in the report.
+#[derive(Debug)]
+struct PartRow {
+    part: u8,
+    answer: Option<Answer>,
+    elapsed: Duration,
+    verdict: Verdict,
+    /// "TIMEOUT" or "PANIC" if the part did not finish
+    failure: Option<&'static str>,
+}
+
+/// A day as it appears in the report.
+#[derive(Debug)]
+struct DayRow {
+    day: u8,
+    title: &'static str,
+    /// Hash of the input, see `aoc_runner::input_hash`
+    input: Option<String>,
+    parts: Vec<PartRow>,
+    /// Why the day has no parts, e.g. a missing input
+    error: Option<String>,
+    /// Passed and total number of fixtures
+    fixtures: (usize, usize),
+}
+
+fn collect(args: &ReportArgs, registry: &AnswerRegistry) -> Vec<DayRow> {
+    select_days(&args.selection.days).into_iter().map(|entry| {
+        let fixtures = match load_fixtures(&args.fixtures, entry.day) {
+            Ok(fixtures) => fixtures,
+            Err(err) => {
+                eprintln!("Day {}: could not load the fixtures: {}", entry.day, err);
+                Vec::new()
+            }
+        };
+        let passed = fixtures.iter().filter(|fixture| fixture.check(entry, &args.limits).is_empty()).count();
+        let mut row = DayRow { day: entry.day, title: entry.title, input: None, parts: Vec::new(), error: None, fixtures: (passed, fixtures.len()) };
+
+        let path = input_path(&args.selection.inputs, entry.day);
+        let contents = match fs::read_to_string(&path) {
+            Ok(contents) => contents,
+            Err(err) => {
+                row.error = Some(format!("could not read {}: {}", path.display(), err));
+                return row;
+            }
+        };
+        let hash = aoc_runner::input_hash(&contents);
+        match entry.solve_within(&contents, args.selection.part, &args.limits) {
+            Ok(results) => row.parts = results.into_iter().map(|result| PartRow {
+                part: result.part,
+                verdict: check(registry.get(entry.day, &hash, result.part), &result.answer),
+                failure: result.failure(),
+                answer: result.answer,
+                elapsed: result.elapsed,
+            }).collect(),
+            Err(error) => row.error = Some(format!("{}: line {}, column {}: {}", path.display(), error.line, error.column, error)),
+        }
+        row.input = Some(hash);
+        row
+    }).collect()
+}
+
+/// Length of the runtime bar relative to the slowest part, on a log scale
+/// since the parts range from nanoseconds to seconds.
+fn bar(elapsed: Duration, slowest: Duration) -> f64 {
+    let scale = |duration: Duration| (duration.as_nanos() as f64 + 1.0).ln();
+    if slowest.is_zero() {
+        return 0.0;
+    }
+    scale(elapsed) / scale(slowest)
+}
+
+fn verdict_text(part: &PartRow) -> String {
+    if let Some(failure) = part.failure {
+        return failure.to_string();
+    }
+    match &part.verdict {
+        Verdict::Correct => String::from("ok"),
+        Verdict::Wrong { expected } => format!("MISMATCH, expected {}", expected),
+        Verdict::Unknown | Verdict::Recorded => String::from("unrecorded"),
+    }
+}
+
+fn answer_text(answer: &Option<Answer>) -> String {
+    answer.as_ref().map_or(String::from("No result"), |answer| answer.to_string())
+}
+
+fn fixtures_text((passed, total): (usize, usize)) -> String {
+    match total {
+        0 => String::from("none"),
+        _ => format!("{}/{} passed", passed, total),
+    }
+}
+
+fn slowest(days: &[DayRow]) -> Duration {
+    days.iter().flat_map(|day| &day.parts).map(|part| part.elapsed).max().unwrap_or_default()
+}
+
+fn total(days: &[DayRow]) -> Duration {
+    days.iter().flat_map(|day| &day.parts).map(|part| part.elapsed).sum()
+}
+
+fn markdown(days: &[DayRow]) -> String {
+    // answers may span lines or contain pipes, neither fits into a cell
+    let cell = |text: &str| text.replace('|', "\\|").replace('\n', "<br>");
+    let slowest = slowest(days);
+    let mut out = String::from("# Advent of Code 2015\n\n");
+    out.push_str("| Day | Part | Answer | Time | Runtime (log scale) | Check | Fixtures | Input |\n");
+    out.push_str("|----:|-----:|--------|-----:|---------------------|-------|----------|-------|\n");
+    for day in days {
+        let name = format!("{} {}", day.day, day.title);
+        let input = day.input.as_ref().map_or(String::new(), |hash| format!("`{}`", &hash[..12]));
+        if let Some(error) = &day.error {
+            writeln!(out, "| {} | | {} | | | | {} | {} |", name, cell(error), fixtures_text(day.fixtures), input).unwrap();
+        }
+        for part in &day.parts {
+            let width = (bar(part.elapsed, slowest) * 20.0).round() as usize;
+            writeln!(out, "| {} | {} | {} | {:.1?} | `{}` | {} | {} | {} |", name, part.part, cell(&answer_text(&part.answer)),
+                     part.elapsed, "█".repeat(width), cell(&verdict_text(part)), fixtures_text(day.fixtures), input).unwrap();
+        }
+    }
+    writeln!(out, "\nTotal runtime: {:.1?}", total(days)).unwrap();
+    out
+}
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;").replace('<', "&lt;").replace('>', "&gt;").replace('"', "&quot;")
+}
+
+const STYLE: &str = "body { font-family: sans-serif; margin: 2em; }
+table { border-collapse: collapse; }
+th, td { padding: 0.3em 0.8em; border-bottom: 1px solid #ddd; text-align: left; vertical-align: top; }
+td.number { text-align: right; }
+td.chart { width: 20em; }
+div.bar { background: #4a7ab5; height: 1em; }
+.ok { color: #2a7d2a; }
+.mismatch, .error { color: #b52a2a; font-weight: bold; }
+pre { margin: 0; }";
+
+fn html(days: &[DayRow]) -> String {
+    let slowest = slowest(days);
+    let mut out = String::from("<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n<title>Advent of Code 2015</title>\n");
+    writeln!(out, "<style>\n{}\n</style>\n</head>\n<body>\n<h1>Advent of Code 2015</h1>\n<table>", STYLE).unwrap();
+    out.push_str("<tr><th>Day</th><th>Part</th><th>Answer</th><th>Time</th><th>Runtime (log scale)</th><th>Check</th><th>Fixtures</th><th>Input</th></tr>\n");
+    for day in days {
+        let name = format!("{} {}", day.day, escape(day.title));
+        let input = day.input.as_ref().map_or(String::new(), |hash| format!("<code>{}</code>", &hash[..12]));
+        let fixtures = fixtures_text(day.fixtures);
+        if let Some(error) = &day.error {
+            writeln!(out, "<tr><td>{}</td><td></td><td class=\"error\" colspan=\"4\">{}</td><td>{}</td><td>{}</td></tr>",
+                     name, escape(error), fixtures, input).unwrap();
+        }
+        for part in &day.parts {
+            let answer = match &part.answer {
+                Some(Answer::Block(block)) => format!("<pre>{}</pre>", escape(block)),
+                answer => escape(&answer_text(answer)),
+            };
+            let class = match part.verdict {
+                _ if part.failure.is_some() => "error",
+                Verdict::Correct => "ok",
+                Verdict::Wrong { .. } => "mismatch",
+                Verdict::Unknown | Verdict::Recorded => "unknown",
+            };
+            writeln!(out, "<tr><td>{}</td><td class=\"number\">{}</td><td>{}</td><td class=\"number\">{:.1?}</td>\
+                           <td class=\"chart\"><div class=\"bar\" style=\"width: {:.1}%\"></div></td>\
+                           <td class=\"{}\">{}</td><td>{}</td><td>{}</td></tr>",
+                     name, part.part, answer, part.elapsed, bar(part.elapsed, slowest) * 100.0,
+                     class, escape(&verdict_text(part)), fixtures, input).unwrap();
+        }
+    }
+    writeln!(out, "</table>\n<p>Total runtime: {:.1?}</p>\n</body>\n</html>", total(days)).unwrap();
+    out
+}
+
+/// Run the selected days and write the report.
+///
+/// Returns false if the report could not be written, wrong answers only
+/// show up in the report.
+pub fn report(args: &ReportArgs) -> bool {
+    let registry = match AnswerRegistry::load(&args.answers) {
+        Ok(registry) => registry,
+        Err(err) => {
+            eprintln!("Could not load {}: {}", args.answers.display(), err);
+            return false;
+        }
+    };
+    let days = collect(args, &registry);
+
+    if let Err(err) = fs::create_dir_all(&args.out) {
+        eprintln!("Could not create {}: {}", args.out.display(), err);
+        return false;
+    }
+    let mut success = true;
+    for (name, contents) in [("report.html", html(&days)), ("report.md", markdown(&days))] {
+        let path = args.out.join(name);
+        match fs::write(&path, contents) {
+            Ok(()) => println!("Wrote {}", path.display()),
+            Err(err) => {
+                eprintln!("Could not write {}: {}", path.display(), err);
+                success = false;
+            }
+        }
+    }
+    success
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn days() -> Vec<DayRow> {
+        let part = |part, answer: &str, nanos, verdict| PartRow { part, answer: Some(Answer::from(answer)), elapsed: Duration::from_nanos(nanos), verdict, failure: None };
+        vec![
+            DayRow {
+                day: 1,
+                title: "Not Quite Lisp",
+                input: Some(String::from("0cc175b9c0f1b6a831c399e269772661")),
+                parts: vec![part(1, "a|b", 999, Verdict::Correct), part(2, "3", 999_999, Verdict::Wrong { expected: Answer::from(4) })],
+                error: None,
+                fixtures: (2, 3),
+            },
+            DayRow { day: 2, title: "<Wrapping>", input: None, parts: Vec::new(), error: Some(String::from("could not read day02.txt")), fixtures: (0, 0) },
+        ]
+    }
+
+    #[test]
+    fn test_bar() {
+        assert_eq!(1.0, bar(Duration::from_millis(5), Duration::from_millis(5)));
+        assert_eq!(0.5, bar(Duration::from_nanos(999), Duration::from_nanos(999_999)));
+        assert_eq!(0.0, bar(Duration::ZERO, Duration::ZERO));
+    }
+
+    #[test]
+    fn test_markdown() {
+        let lines: Vec<String> = markdown(&days()).lines().map(String::from).collect();
+        assert_eq!("| 1 Not Quite Lisp | 1 | a\\|b | 999.0ns | `██████████` | ok | 2/3 passed | `0cc175b9c0f1` |", lines[4]);
+        assert_eq!("| 1 Not Quite Lisp | 2 | 3 | 1000.0µs | `████████████████████` | MISMATCH, expected 4 | 2/3 passed | `0cc175b9c0f1` |", lines[5]);
+        assert_eq!("| 2 <Wrapping> | | could not read day02.txt | | | | none |  |", lines[6]);
+        assert_eq!("Total runtime: 1.0ms", lines[8]);
+    }
+
+    #[test]
+    fn test_failed_part() {
+        let mut days = days();
+        days[0].parts[0].answer = None;
+        days[0].parts[0].failure = Some("TIMEOUT");
+        assert_eq!("| 1 Not Quite Lisp | 1 | No result | 999.0ns | `██████████` | TIMEOUT | 2/3 passed | `0cc175b9c0f1` |",
+                   markdown(&days).lines().nth(4).unwrap());
+        assert!(html(&days).contains("<td class=\"error\">TIMEOUT</td>"));
+    }
+
+    #[test]
+    fn test_html() {
+        let html = html(&days());
+        assert!(html.contains("<td>2 &lt;Wrapping&gt;</td>"));
+        assert!(html.contains("<div class=\"bar\" style=\"width: 50.0%\"></div>"));
+        assert!(html.contains("<td class=\"mismatch\">MISMATCH, expected 4</td>"));
+    }
+}
@@ -0,0 +1,289 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use aoc_runner::input_path;
+use clap::Args;
+
+#[derive(Debug, Args)]
+pub struct NewDayArgs {
+    /// Day to create
+    #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+    day: u8,
+
+    /// Title of the puzzle
+    #[arg(long, default_value = "CHANGEME")]
+    title: String,
+
+    /// Root of the workspace to add the day to
+    #[arg(long, default_value = ".")]
+    root: PathBuf,
+
+    /// Solution template [default: <root>/template.rs]
+    #[arg(long)]
+    template: Option<PathBuf>,
+}
+
+fn crate_name(day: u8) -> String {
+    format!("day{:02}", day)
+}
+
+/// Fill in the placeholders of the template for `day`.
+fn render_template(template: &str, day: u8, title: &str) -> String {
+    template.replace("DayXX", &format!("Day{:02}", day))
+            .replace("const DAY: u8 = 0;", &format!("const DAY: u8 = {};", day))
+            .replace("\"CHANGEME\"", &format!("{:?}", title))
+}
+
+fn manifest(day: u8) -> String {
+    format!("[package]
+name = \"{0}\"
+version = \"0.1.0\"
+edition = \"2021\"
+
+# See more keys and their definitions at https://doc.rust-lang.org/cargo/reference/manifest.html
+
+[[bin]]
+name = \"{0}\"
+path = \"src/main.rs\"
+required-features = [\"cli\"]
+
+[features]
+default = [\"cli\"]
+cli = [\"aoc-runner/cli\"]
+
+[dependencies]
+aoc-runner = {{ path = \"../aoc-runner\", default-features = false }}
+", crate_name(day))
+}
+
+fn main_rs(day: u8) -> String {
+    format!("aoc_runner::main!({}::Day{:02});\n", crate_name(day), day)
+}
+
+/// Add `member` to the `members` of the workspace manifest, keeping the list
+/// sorted.
+fn add_member(workspace: &str, member: &str) -> Result<String, String> {
+    let start = workspace.find("members = [").ok_or("no members list in the workspace manifest")?
+                + "members = [".len();
+    let end = start + workspace[start..].find(']').ok_or("unterminated members list")?;
+    let mut members: Vec<&str> = workspace[start..end].split(',')
+        .map(|member| member.trim().trim_matches('"'))
+        .filter(|member| !member.is_empty())
+        .collect();
+    if members.contains(&member) {
+        return Err(format!("{} is already a member of the workspace", member));
+    }
+    let position = members.iter().position(|other| *other > member).unwrap_or(members.len());
+    members.insert(position, member);
+    let list: Vec<String> = members.iter().map(|member| format!("    \"{}\"", member)).collect();
+    Ok(format!("{}\n{}\n{}", &workspace[..start], list.join(",\n"), &workspace[end..]))
+}
+
+/// Insert `line` among the lines of `text` starting with `prefix`, before
+/// the first one sorting after it, `None` if there are no such lines.
+fn insert_sorted(text: &str, prefix: &str, line: &str) -> Option<String> {
+    let mut offset = 0;
+    let mut position = None;
+    for existing in text.split_inclusive('\n') {
+        if existing.starts_with(prefix) {
+            if existing > line {
+                position = Some(offset);
+                break;
+            }
+            position = Some(offset + existing.len());
+        }
+        offset += existing.len();
+    }
+    position.map(|position| format!("{}{}{}", &text[..position], line, &text[position..]))
+}
+
+fn dependency(day: u8) -> String {
+    format!("{0} = {{ path = \"../{0}\", default-features = false }}\n", crate_name(day))
+}
+
+fn reexport(day: u8) -> String {
+    format!("pub use {};\n", crate_name(day))
+}
+
+fn day_entry(day: u8) -> String {
+    format!("    DayEntry::new::<{}::Day{:02}>(),\n", crate_name(day), day)
+}
+
+/// Add the day to the dependencies in the manifest of aoc2015.
+fn add_dependency(manifest: &str, day: u8) -> Result<String, String> {
+    if manifest.lines().any(|line| line.split_whitespace().next() == Some(&crate_name(day))) {
+        return Err(format!("{} is already a dependency", crate_name(day)));
+    }
+    insert_sorted(manifest, "day", &dependency(day)).ok_or_else(|| String::from("no days among the dependencies"))
+}
+
+/// Re-export the day from the `lib.rs` of aoc2015 and add it to `DAYS`.
+fn register_day(lib: &str, day: u8) -> Result<String, String> {
+    if lib.contains(&reexport(day)) {
+        return Err(format!("{} is already re-exported", crate_name(day)));
+    }
+    let lib = insert_sorted(lib, "pub use ", &reexport(day)).ok_or("no re-exports")?;
+    let lib = insert_sorted(&lib, "    DayEntry::new::<", &day_entry(day)).ok_or("no days in DAYS")?;
+    let start = lib.find("pub static DAYS: [DayEntry; ").ok_or("no DAYS")? + "pub static DAYS: [DayEntry; ".len();
+    let end = start + lib[start..].find(']').ok_or("unterminated type of DAYS")?;
+    Ok(format!("{}{}{}", &lib[..start], lib.matches("    DayEntry::new::<").count(), &lib[end..]))
+}
+
+fn read(path: &Path) -> Result<String, String> {
+    fs::read_to_string(path).map_err(|err| format!("Could not read {}: {}", path.display(), err))
+}
+
+fn write(path: &Path, contents: &str) -> Result<(), String> {
+    fs::write(path, contents).map_err(|err| format!("Could not write {}: {}", path.display(), err))
+}
+
+fn create_dir(path: &Path) -> Result<(), String> {
+    fs::create_dir_all(path).map_err(|err| format!("Could not create {}: {}", path.display(), err))
+}
+
+/// The manifest and `lib.rs` of aoc2015 with the day registered, `None` if
+/// there is no aoc2015 crate in the workspace.
+fn register(root: &Path, day: u8) -> Result<Option<[(PathBuf, String); 2]>, String> {
+    let manifest_path = root.join("aoc2015").join("Cargo.toml");
+    let lib_path = root.join("aoc2015").join("src").join("lib.rs");
+    if !manifest_path.exists() {
+        return Ok(None);
+    }
+    let manifest = add_dependency(&read(&manifest_path)?, day)
+        .map_err(|err| format!("{}: {}", manifest_path.display(), err))?;
+    let lib = register_day(&read(&lib_path)?, day)
+        .map_err(|err| format!("{}: {}", lib_path.display(), err))?;
+    Ok(Some([(manifest_path, manifest), (lib_path, lib)]))
+}
+
+/// Create the day, returns whether it was registered in aoc2015.
+fn create(args: &NewDayArgs, dir: &Path) -> Result<bool, String> {
+    if dir.exists() {
+        return Err(format!("{} already exists", dir.display()));
+    }
+    // check everything before creating the first file
+    let template = read(&args.template.clone().unwrap_or_else(|| args.root.join("template.rs")))?;
+    let workspace_path = args.root.join("Cargo.toml");
+    let workspace = add_member(&read(&workspace_path)?, &crate_name(args.day))
+        .map_err(|err| format!("{}: {}", workspace_path.display(), err))?;
+    let registered = register(&args.root, args.day)?;
+
+    create_dir(&dir.join("src"))?;
+    write(&dir.join("Cargo.toml"), &manifest(args.day))?;
+    write(&dir.join("src").join("lib.rs"), &render_template(&template, args.day, &args.title))?;
+    write(&dir.join("src").join("main.rs"), &main_rs(args.day))?;
+    let inputs = args.root.join("inputs");
+    let input = input_path(&inputs, args.day);
+    if !input.exists() {
+        create_dir(&inputs)?;
+        write(&input, "")?;
+    }
+    write(&workspace_path, &workspace)?;
+    for (path, contents) in registered.iter().flatten() {
+        write(path, contents)?;
+    }
+    Ok(registered.is_some())
+}
+
+/// Create the crate of a new day from the template, with an empty input
+/// file, add it to the workspace and register it in aoc2015.
+///
+/// Returns false if the day already exists or anything went wrong.
+pub fn new_day(args: &NewDayArgs) -> bool {
+    let dir = args.root.join(crate_name(args.day));
+    match create(args, &dir) {
+        Ok(registered) => {
+            println!("Created {}, put your puzzle input into {}", dir.display(),
+                     input_path(&args.root.join("inputs"), args.day).display());
+            if !registered {
+                println!("There is no aoc2015 crate in {}, to run the day from it add", args.root.display());
+                println!("    {}\nto the [dependencies] of aoc2015/Cargo.toml,", dependency(args.day).trim_end());
+                println!("    {}\nto aoc2015/src/lib.rs and", reexport(args.day).trim_end());
+                println!("{}\nto its DAYS.", day_entry(args.day).trim_end());
+            }
+            true
+        },
+        Err(err) => {
+            eprintln!("{}", err);
+            false
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render_template() {
+        let template = "pub struct DayXX;\nimpl Solution for DayXX {\n    const DAY: u8 = 0;\n    const TITLE: &'static str = \"CHANGEME\";\n";
+        let expected = "pub struct Day07;\nimpl Solution for Day07 {\n    const DAY: u8 = 7;\n    const TITLE: &'static str = \"Some \\\"Assembly\\\" Required\";\n";
+        assert_eq!(expected, render_template(template, 7, "Some \"Assembly\" Required"));
+    }
+
+    #[test]
+    fn test_add_member() {
+        let workspace = "[workspace]\nresolver = \"2\"\n\nmembers = [\n    \"aoc-runner\",\n    \"day01\",\n    \"day03\"\n]\n";
+        let expected = "[workspace]\nresolver = \"2\"\n\nmembers = [\n    \"aoc-runner\",\n    \"day01\",\n    \"day02\",\n    \"day03\"\n]\n";
+        assert_eq!(Ok(String::from(expected)), add_member(workspace, "day02"));
+        assert!(add_member(workspace, "day03").is_err());
+        assert!(add_member("[workspace]\n", "day02").is_err());
+    }
+
+    #[test]
+    fn test_register_day() {
+        let manifest = "[dependencies]\naoc-runner = { path = \"../aoc-runner\" }\nday01 = { path = \"../day01\", default-features = false }\nday03 = { path = \"../day03\", default-features = false }\nrand = \"0.10\"\n";
+        let expected = "[dependencies]\naoc-runner = { path = \"../aoc-runner\" }\nday01 = { path = \"../day01\", default-features = false }\nday02 = { path = \"../day02\", default-features = false }\nday03 = { path = \"../day03\", default-features = false }\nrand = \"0.10\"\n";
+        assert_eq!(Ok(String::from(expected)), add_dependency(manifest, 2));
+        assert!(add_dependency(manifest, 3).is_err());
+
+        let lib = "pub use aoc_runner::Solution;\npub use day01;\npub use day03;\n\npub static DAYS: [DayEntry; 2] = [\n    DayEntry::new::<day01::Day01>(),\n    DayEntry::new::<day03::Day03>(),\n];\n";
+        let expected = "pub use aoc_runner::Solution;\npub use day01;\npub use day03;\npub use day04;\n\npub static DAYS: [DayEntry; 3] = [\n    DayEntry::new::<day01::Day01>(),\n    DayEntry::new::<day03::Day03>(),\n    DayEntry::new::<day04::Day04>(),\n];\n";
+        assert_eq!(Ok(String::from(expected)), register_day(lib, 4));
+        assert!(register_day(lib, 1).is_err());
+        assert!(register_day("pub use day01;\n", 2).is_err());
+    }
+
+    #[test]
+    fn test_new_day() {
+        let root = std::env::temp_dir().join(format!("aoc2015-new-day-{}", std::process::id()));
+        fs::create_dir_all(&root).unwrap();
+        fs::write(root.join("Cargo.toml"), "[workspace]\nmembers = [\n    \"aoc-runner\"\n]\n").unwrap();
+        fs::write(root.join("template.rs"), "pub struct DayXX;\n").unwrap();
+        let args = NewDayArgs { day: 9, title: String::from("All in a Single Night"), root: root.clone(), template: None };
+
+        assert!(new_day(&args));
+        assert_eq!("pub struct Day09;\n", fs::read_to_string(root.join("day09/src/lib.rs")).unwrap());
+        assert!(fs::read_to_string(root.join("day09/src/main.rs")).unwrap().contains("day09::Day09"));
+        let manifest = fs::read_to_string(root.join("day09/Cargo.toml")).unwrap();
+        assert!(manifest.contains("name = \"day09\""));
+        assert!(manifest.contains("required-features = [\"cli\"]"));
+        assert_eq!("", fs::read_to_string(root.join("inputs/day09.txt")).unwrap());
+        assert!(fs::read_to_string(root.join("Cargo.toml")).unwrap().contains("\"day09\""));
+        // the day exists now
+        assert!(!new_day(&args));
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn test_new_day_registered() {
+        let root = std::env::temp_dir().join(format!("aoc2015-new-day-registered-{}", std::process::id()));
+        fs::create_dir_all(root.join("aoc2015/src")).unwrap();
+        fs::write(root.join("Cargo.toml"), "[workspace]\nmembers = [\n    \"aoc2015\"\n]\n").unwrap();
+        fs::write(root.join("template.rs"), "pub struct DayXX;\n").unwrap();
+        // the real manifest and registry of this crate
+        let crate_dir = Path::new(env!("CARGO_MANIFEST_DIR"));
+        let dependency = "day07 = { path = \"../day07\", default-features = false }\n";
+        let manifest = fs::read_to_string(crate_dir.join("Cargo.toml")).unwrap();
+        let lib = fs::read_to_string(crate_dir.join("src/lib.rs")).unwrap()
+            .replace("pub use day07;\n", "").replace("    DayEntry::new::<day07::Day07>(),\n", "").replace("[DayEntry; 25]", "[DayEntry; 24]");
+        fs::write(root.join("aoc2015/Cargo.toml"), manifest.replace(dependency, "")).unwrap();
+        fs::write(root.join("aoc2015/src/lib.rs"), &lib).unwrap();
+        let args = NewDayArgs { day: 7, title: String::from("Some Assembly Required"), root: root.clone(), template: None };
+
+        assert!(new_day(&args));
+        assert_eq!(manifest, fs::read_to_string(root.join("aoc2015/Cargo.toml")).unwrap());
+        assert_eq!(fs::read_to_string(crate_dir.join("src/lib.rs")).unwrap(), fs::read_to_string(root.join("aoc2015/src/lib.rs")).unwrap());
+        fs::remove_dir_all(&root).unwrap();
+    }
+}
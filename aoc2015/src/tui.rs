@@ -0,0 +1,381 @@
+use std::collections::BTreeMap;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::thread;
+use std::time::Duration;
+
+use aoc2015::DAYS;
+use aoc_runner::{execute, fixture_dir, input_path, load_fixtures, parse_timeout, Answer, DayEntry, InputSource, Instruments, Limits, PartResult};
+use clap::Args;
+use ratatui::crossterm::event::{self, Event, KeyCode, KeyEventKind};
+use ratatui::layout::{Constraint, Layout};
+use ratatui::style::{Modifier, Style};
+use ratatui::text::Line;
+use ratatui::widgets::{Block, List, ListState, Paragraph, Wrap};
+use ratatui::{DefaultTerminal, Frame};
+
+#[derive(Debug, Args)]
+pub struct TuiArgs {
+    /// Directory containing the puzzle inputs, named dayNN.txt
+    #[arg(long, default_value = "inputs")]
+    inputs: PathBuf,
+
+    /// Directory containing the example inputs of the days
+    #[arg(long, default_value = "fixtures")]
+    fixtures: PathBuf,
+
+    /// Give up on a part after this many seconds and show it as TIMEOUT
+    #[arg(long, value_parser = parse_timeout, default_value = "60")]
+    timeout: Duration,
+}
+
+/// An input a day can run on.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct InputChoice {
+    /// "real", "example (<name>)" or "file"
+    label: String,
+    path: PathBuf,
+    /// Answers the input has to give, known for the examples
+    expected: BTreeMap<u8, Answer>,
+}
+
+/// The real input of `day` followed by its examples.
+fn input_choices(inputs: &Path, fixtures: &Path, day: u8) -> Vec<InputChoice> {
+    let mut choices = vec![InputChoice { label: String::from("real"), path: input_path(inputs, day), expected: BTreeMap::new() }];
+    // broken fixtures show up in `cargo test`, here they are just not offered
+    for fixture in load_fixtures(fixtures, day).unwrap_or_default() {
+        choices.push(InputChoice {
+            label: format!("example ({})", fixture.name),
+            path: fixture_dir(fixtures, day).join(format!("{}.txt", fixture.name)),
+            expected: fixture.expected,
+        });
+    }
+    choices
+}
+
+/// Results of the last run of a day.
+#[derive(Debug)]
+struct Run {
+    input: InputChoice,
+    /// The parts, or why the input could not be used
+    outcome: Result<Vec<PartResult>, String>,
+}
+
+/// A day in the list.
+struct DayView {
+    entry: DayEntry,
+    inputs: Vec<InputChoice>,
+    input: usize,
+    last: Option<Run>,
+    running: bool,
+}
+
+/// The answer of a part, or why it has none.
+fn answer_text(result: &PartResult) -> String {
+    match (result.failure(), &result.answer) {
+        (Some(failure), _) => failure.to_string(),
+        (None, Some(answer)) => answer.to_string(),
+        (None, None) => String::from("No result"),
+    }
+}
+
+impl DayView {
+    /// Answers and runtime of the last run, for the list.
+    fn summary(&self) -> String {
+        if self.running {
+            return String::from("running...");
+        }
+        match &self.last {
+            None => String::new(),
+            Some(Run { outcome: Err(_), .. }) => String::from("error"),
+            Some(Run { outcome: Ok(results), .. }) => {
+                let answers: Vec<String> = results.iter()
+                    .map(|result| match (result.failure(), &result.answer) {
+                        (None, None) => String::from("-"),
+                        _ => answer_text(result).replace('\n', " "),
+                    })
+                    .collect();
+                let elapsed: Duration = results.iter().map(|result| result.elapsed).sum();
+                format!("{}  ({:.1?})", answers.join(" / "), elapsed)
+            }
+        }
+    }
+}
+
+/// A finished run of the day at `index` in the list.
+type Finished = (usize, Run);
+
+struct App {
+    days: Vec<DayView>,
+    list: ListState,
+    /// Path typed after pressing `o`, None when not typing
+    prompt: Option<String>,
+    sender: Sender<Finished>,
+    receiver: Receiver<Finished>,
+    limits: Limits,
+    quit: bool,
+}
+
+impl App {
+    fn new(inputs: &Path, fixtures: &Path, timeout: Duration) -> App {
+        let days = DAYS.iter().map(|entry| DayView {
+            entry: *entry,
+            inputs: input_choices(inputs, fixtures, entry.day),
+            input: 0,
+            last: None,
+            running: false,
+        }).collect();
+        let (sender, receiver) = mpsc::channel();
+        let limits = Limits { jobs: None, timeout: Some(timeout) };
+        App { days, list: ListState::default().with_selected(Some(0)), prompt: None, sender, receiver, limits, quit: false }
+    }
+
+    fn selected(&mut self) -> &mut DayView {
+        &mut self.days[self.list.selected().unwrap_or_default()]
+    }
+
+    /// Run `part` (or both parts) of the selected day on its input in the
+    /// background, so the interface keeps responding to slow days. A part
+    /// running into the timeout keeps running in the background until the
+    /// interface exits.
+    fn run(&mut self, part: Option<u8>) {
+        let index = self.list.selected().unwrap_or_default();
+        let day = &mut self.days[index];
+        if day.running {
+            return;
+        }
+        day.running = true;
+        let (entry, input, sender, limits) = (day.entry, day.inputs[day.input].clone(), self.sender.clone(), self.limits);
+        thread::spawn(move || {
+            let outcome = entry.prepare(&InputSource::File(input.path.clone())).map(|prepared| {
+                let tasks = (1..=2).filter(|p| part.is_none_or(|part| part == *p)).map(|part| (prepared.clone(), part)).collect();
+                let mut results = Vec::new();
+                execute(tasks, &limits, &Instruments { explain: true, profile_mem: false }, |_, result| results.push(result));
+                results.sort_by_key(|result| result.part);
+                results
+            });
+            // the interface may be gone already
+            let _ = sender.send((index, Run { input, outcome: outcome.map_err(|failure| failure.to_string()) }));
+        });
+    }
+
+    fn finish(&mut self, (index, run): Finished) {
+        self.days[index].running = false;
+        self.days[index].last = Some(run);
+    }
+
+    fn handle_key(&mut self, key: KeyCode) {
+        if let Some(prompt) = &mut self.prompt {
+            match key {
+                KeyCode::Char(c) => prompt.push(c),
+                KeyCode::Backspace => {
+                    prompt.pop();
+                },
+                // there is no file without a name
+                KeyCode::Enter if prompt.trim().is_empty() => (),
+                KeyCode::Enter => {
+                    let path = PathBuf::from(self.prompt.take().unwrap());
+                    let day = self.selected();
+                    day.inputs.push(InputChoice { label: String::from("file"), path, expected: BTreeMap::new() });
+                    day.input = day.inputs.len() - 1;
+                },
+                KeyCode::Esc => self.prompt = None,
+                _ => (),
+            }
+            return;
+        }
+        match key {
+            KeyCode::Char('q') | KeyCode::Esc => self.quit = true,
+            KeyCode::Down | KeyCode::Char('j') => self.list.select(Some((self.list.selected().unwrap_or_default() + 1) % self.days.len())),
+            KeyCode::Up | KeyCode::Char('k') => {
+                let selected = self.list.selected().unwrap_or_default();
+                self.list.select(Some((selected + self.days.len() - 1) % self.days.len()));
+            },
+            KeyCode::Enter => self.run(None),
+            KeyCode::Char('1') => self.run(Some(1)),
+            KeyCode::Char('2') => self.run(Some(2)),
+            // toggle between the real input and the examples
+            KeyCode::Tab => {
+                let day = self.selected();
+                day.input = (day.input + 1) % day.inputs.len();
+            },
+            KeyCode::BackTab => {
+                let day = self.selected();
+                day.input = (day.input + day.inputs.len() - 1) % day.inputs.len();
+            },
+            KeyCode::Char('o') => self.prompt = Some(String::new()),
+            _ => (),
+        }
+    }
+
+    fn details(&self) -> Vec<Line<'static>> {
+        let day = &self.days[self.list.selected().unwrap_or_default()];
+        let input = &day.inputs[day.input];
+        let mut lines = vec![
+            Line::styled(format!("Day {}: {}", day.entry.day, day.entry.title), Style::new().add_modifier(Modifier::BOLD)),
+            Line::from(format!("Input: {} ({}), {} of {}", input.label, input.path.display(), day.input + 1, day.inputs.len())),
+            Line::from(""),
+        ];
+        let Some(run) = &day.last else {
+            return lines;
+        };
+        lines.push(Line::from(format!("Last run on {}:", run.input.label)));
+        let results = match &run.outcome {
+            Ok(results) => results,
+            Err(error) => {
+                lines.push(Line::from(error.clone()));
+                return lines;
+            }
+        };
+        for result in results {
+            let answer = answer_text(result);
+            let check = match run.input.expected.get(&result.part) {
+                Some(expected) if result.answer.as_ref() == Some(expected) => String::from(", as expected"),
+                Some(expected) => format!(", expected {}", expected),
+                None => String::new(),
+            };
+            let mut answer_lines = answer.lines();
+            let over = if result.timed_out { ">" } else { "" };
+            lines.push(Line::from(format!("Part {}: {}  ({}{:.1?}{})", result.part, answer_lines.next().unwrap_or_default(), over, result.elapsed, check)));
+            lines.extend(answer_lines.map(|line| Line::from(format!("    {}", line))));
+            lines.extend(result.trace.iter().map(|event| Line::from(format!("    {}", event))));
+        }
+        lines
+    }
+
+    fn draw(&mut self, frame: &mut Frame) {
+        let [main, help] = Layout::vertical([Constraint::Min(0), Constraint::Length(1)]).areas(frame.area());
+        let [left, right] = Layout::horizontal([Constraint::Percentage(55), Constraint::Percentage(45)]).areas(main);
+
+        let items: Vec<String> = self.days.iter()
+            .map(|day| format!("{:02} {:<40} {}", day.entry.day, day.entry.title, day.summary()))
+            .collect();
+        let list = List::new(items)
+            .block(Block::bordered().title("Advent of Code 2015"))
+            .highlight_style(Style::new().add_modifier(Modifier::REVERSED));
+        frame.render_stateful_widget(list, left, &mut self.list);
+
+        let details = Paragraph::new(self.details()).block(Block::bordered().title("Details")).wrap(Wrap { trim: false });
+        frame.render_widget(details, right);
+
+        let help_text = match &self.prompt {
+            Some(prompt) => format!("Input file: {}_  (Enter to use, Esc to cancel)", prompt),
+            None => String::from("↑↓ select  Enter run  1/2 run part  Tab switch input  o open file  q quit"),
+        };
+        frame.render_widget(Paragraph::new(help_text), help);
+    }
+
+    fn event_loop(&mut self, terminal: &mut DefaultTerminal) -> io::Result<()> {
+        while !self.quit {
+            terminal.draw(|frame| self.draw(frame))?;
+            if event::poll(Duration::from_millis(100))? {
+                if let Event::Key(key) = event::read()? {
+                    if key.kind == KeyEventKind::Press {
+                        self.handle_key(key.code);
+                    }
+                }
+            }
+            while let Ok(finished) = self.receiver.try_recv() {
+                self.finish(finished);
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Browse the days and run them interactively.
+///
+/// Returns false if the terminal could not be used.
+pub fn tui(args: &TuiArgs) -> bool {
+    let mut terminal = match ratatui::try_init() {
+        Ok(terminal) => terminal,
+        Err(err) => {
+            eprintln!("Could not set up the terminal: {}", err);
+            return false;
+        }
+    };
+    let outcome = App::new(&args.inputs, &args.fixtures, args.timeout).event_loop(&mut terminal);
+    ratatui::restore();
+    outcome.inspect_err(|err| eprintln!("Terminal error: {}", err)).is_ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ratatui::backend::TestBackend;
+    use ratatui::Terminal;
+
+    fn app() -> App {
+        let fixtures = Path::new(env!("CARGO_MANIFEST_DIR")).join("../fixtures");
+        App::new(Path::new("inputs"), &fixtures, Duration::from_secs(60))
+    }
+
+    fn screen(app: &mut App) -> String {
+        let mut terminal = Terminal::new(TestBackend::new(140, 30)).unwrap();
+        terminal.draw(|frame| app.draw(frame)).unwrap();
+        let buffer = terminal.backend().buffer();
+        buffer.content().chunks(buffer.area.width as usize)
+            .map(|row| row.iter().map(|cell| cell.symbol()).collect::<String>().trim_end().to_string())
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    #[test]
+    fn test_inputs() {
+        let mut app = app();
+        assert_eq!("real", app.days[0].inputs[0].label);
+        assert!(app.days.iter().all(|day| day.inputs.len() > 1));
+        app.handle_key(KeyCode::Down);
+        app.handle_key(KeyCode::Tab);
+        assert_eq!(1, app.days[1].input);
+        app.handle_key(KeyCode::BackTab);
+        app.handle_key(KeyCode::BackTab);
+        assert_eq!(app.days[1].inputs.len() - 1, app.days[1].input);
+
+        app.handle_key(KeyCode::Char('o'));
+        app.handle_key(KeyCode::Enter);
+        assert_eq!(Some(String::new()), app.prompt);
+        for c in "my.txt".chars() {
+            app.handle_key(KeyCode::Char(c));
+        }
+        assert!(screen(&mut app).contains("Input file: my.txt_"));
+        app.handle_key(KeyCode::Enter);
+        assert_eq!(InputChoice { label: String::from("file"), path: PathBuf::from("my.txt"), expected: BTreeMap::new() }, app.days[1].inputs[app.days[1].input]);
+        assert!(!app.quit);
+        app.handle_key(KeyCode::Char('q'));
+        assert!(app.quit);
+    }
+
+    #[test]
+    fn test_run_example() {
+        let mut app = app();
+        // the examples of day 9 trace the route
+        for _ in 0..8 {
+            app.handle_key(KeyCode::Down);
+        }
+        app.handle_key(KeyCode::Tab);
+        app.handle_key(KeyCode::Enter);
+        assert!(screen(&mut app).contains("running..."));
+        let finished = app.receiver.recv().unwrap();
+        app.finish(finished);
+        let screen = screen(&mut app);
+        assert!(screen.contains("605 / 982"), "{}", screen);
+        assert!(screen.contains("Last run on example (example):"), "{}", screen);
+        assert!(screen.contains("Part 1: 605"), "{}", screen);
+        assert!(screen.contains(", as expected"), "{}", screen);
+        assert!(screen.contains("route: "), "{}", screen);
+    }
+
+    #[test]
+    fn test_failed_parts() {
+        let mut app = app();
+        let failed = |part, timed_out, panicked| PartResult { part, answer: None, elapsed: Duration::from_secs(60), timed_out, panicked, trace: Vec::new(), memory: None };
+        let input = app.days[0].inputs[1].clone();
+        app.finish((0, Run { input, outcome: Ok(vec![failed(1, true, false), failed(2, false, true)]) }));
+        let screen = screen(&mut app);
+        assert!(screen.contains("TIMEOUT / PANIC"), "{}", screen);
+        assert!(screen.contains("Part 1: TIMEOUT  (>60.0s, expected"), "{}", screen);
+        assert!(screen.contains("Part 2: PANIC"), "{}", screen);
+    }
+}
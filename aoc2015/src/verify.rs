@@ -0,0 +1,117 @@
+use std::path::PathBuf;
+
+use aoc_runner::{Answer, AnswerRegistry, Limits};
+use clap::Args;
+
+use crate::{read_input, report_parse_error, select_days, Selection};
+
+#[derive(Debug, Args)]
+pub struct VerifyArgs {
+    #[command(flatten)]
+    selection: Selection,
+
+    /// File containing the expected answers
+    #[arg(long, default_value = "answers.toml")]
+    answers: PathBuf,
+
+    /// Record the current answers as the expected ones instead of checking them
+    #[arg(long)]
+    record: bool,
+
+    #[command(flatten)]
+    limits: Limits,
+}
+
+/// How an answer compares to the recorded one.
+#[derive(Debug, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    Wrong { expected: Answer },
+    Unknown,
+    Recorded,
+}
+
+pub fn check(expected: Option<Answer>, actual: &Option<Answer>) -> Verdict {
+    match expected {
+        None => Verdict::Unknown,
+        Some(expected) if actual.as_ref() == Some(&expected) => Verdict::Correct,
+        Some(expected) => Verdict::Wrong { expected },
+    }
+}
+
+/// Verify (or record) the answers of the selected days.
+///
+/// Returns false if an answer did not match, a part timed out or panicked, or
+/// an input was missing or malformed.
+pub fn verify(args: &VerifyArgs) -> bool {
+    let mut registry = match AnswerRegistry::load(&args.answers) {
+        Ok(registry) => registry,
+        Err(err) => {
+            eprintln!("Could not load {}: {}", args.answers.display(), err);
+            return false;
+        }
+    };
+
+    let mut success = true;
+    for entry in select_days(&args.selection.days) {
+        let Some(contents) = read_input(&args.selection.inputs, entry.day) else {
+            success = false;
+            continue;
+        };
+        let hash = aoc_runner::input_hash(&contents);
+        let results = match entry.solve_within(&contents, args.selection.part, &args.limits) {
+            Ok(results) => results,
+            Err(error) => {
+                report_parse_error(&args.selection.inputs, entry.day, &contents, error);
+                success = false;
+                continue;
+            }
+        };
+        for result in results {
+            if let Some(failure) = result.failure() {
+                println!("Day {:2} Part {}: {}", entry.day, result.part, failure);
+                success = false;
+                continue;
+            }
+            let verdict = if args.record {
+                if let Some(answer) = &result.answer {
+                    registry.record(entry.day, &hash, result.part, answer);
+                }
+                Verdict::Recorded
+            } else {
+                check(registry.get(entry.day, &hash, result.part), &result.answer)
+            };
+            let answer = result.answer.map_or(String::from("No result"), |answer| answer.to_string());
+            match verdict {
+                Verdict::Correct => println!("Day {:2} Part {}: ok", entry.day, result.part),
+                Verdict::Wrong { expected } => {
+                    println!("Day {:2} Part {}: MISMATCH, expected {} but got {}", entry.day, result.part, expected, answer);
+                    success = false;
+                },
+                Verdict::Unknown => println!("Day {:2} Part {}: no recorded answer, got {}", entry.day, result.part, answer),
+                Verdict::Recorded => println!("Day {:2} Part {}: recorded {}", entry.day, result.part, answer),
+            }
+        }
+    }
+
+    if args.record {
+        if let Err(err) = registry.save() {
+            eprintln!("Could not write {}: {}", args.answers.display(), err);
+            return false;
+        }
+    }
+    success
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_check() {
+        assert_eq!(Verdict::Unknown, check(None, &Some(Answer::from(1))));
+        assert_eq!(Verdict::Correct, check(Some(Answer::from(1)), &Some(Answer::from(1usize))));
+        assert_eq!(Verdict::Wrong { expected: Answer::from(1) }, check(Some(Answer::from(1)), &Some(Answer::from(2))));
+        assert_eq!(Verdict::Wrong { expected: Answer::from(1) }, check(Some(Answer::from(1)), &None));
+    }
+}
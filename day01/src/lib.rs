@@ -0,0 +1,235 @@
+#![allow(unused)]
+#![allow(dead_code)]
+use std::collections::BTreeMap;
+use std::io::{self, Read};
+
+use aoc_runner::{lines, Answer, ParseError, Solution};
+
+const EXPECTED: &str = "'(' or ')'";
+
+/// Follows the instructions as they come in, so inputs of any size can be
+/// processed in constant memory.
+///
+/// Positions count instructions starting at 1, position 0 being the start
+/// on the ground floor. Line breaks between instructions are skipped.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FloorTracker {
+    floor: i64,
+    position: usize,
+    lowest: i64,
+    highest: i64,
+    /// Floors to report the first visit of, see [`FloorTracker::watching`]
+    first_visits: BTreeMap<i64, Option<usize>>,
+    // where the next byte is in the input, for errors
+    line: usize,
+    column: usize,
+}
+
+/// A tracker on the ground floor, see [`FloorTracker::new`].
+impl Default for FloorTracker {
+    fn default() -> Self {
+        FloorTracker::new()
+    }
+}
+
+impl FloorTracker {
+    /// A tracker at the start, watching the basement.
+    pub fn new() -> FloorTracker {
+        FloorTracker::watching([])
+    }
+
+    /// A tracker at the start that also remembers when each of `floors` is
+    /// first reached, see [`FloorTracker::first_visit`]. The basement is
+    /// always watched.
+    pub fn watching(floors: impl IntoIterator<Item = i64>) -> FloorTracker {
+        let first_visits = floors.into_iter().chain([-1])
+            .map(|floor| (floor, (floor == 0).then_some(0)))
+            .collect();
+        FloorTracker { floor: 0, position: 0, lowest: 0, highest: 0, first_visits, line: 1, column: 1 }
+    }
+
+    /// Follow the instructions in `bytes`, stopping at the first character
+    /// that is not one.
+    ///
+    /// Columns count characters: everything before the first error is ASCII.
+    /// A character split between two calls is reported as U+FFFD.
+    pub fn feed(&mut self, bytes: &[u8]) -> Result<(), ParseError> {
+        for (index, &byte) in bytes.iter().enumerate() {
+            match byte {
+                b'(' => {
+                    self.floor += 1;
+                    self.position += 1;
+                    // the floors visited so far are exactly lowest..=highest
+                    if self.floor > self.highest {
+                        self.highest = self.floor;
+                        self.reached();
+                    }
+                },
+                b')' => {
+                    self.floor -= 1;
+                    self.position += 1;
+                    if self.floor < self.lowest {
+                        self.lowest = self.floor;
+                        self.reached();
+                    }
+                },
+                b'\n' => {
+                    self.line += 1;
+                    self.column = 0;
+                },
+                b'\r' => (),
+                _ => return Err(ParseError::new(self.line, self.column, EXPECTED, &first_char(&bytes[index..]))),
+            }
+            self.column += 1;
+        }
+        Ok(())
+    }
+
+    fn reached(&mut self) {
+        if let Some(visit @ None) = self.first_visits.get_mut(&self.floor) {
+            *visit = Some(self.position);
+        }
+    }
+
+    /// Follow all instructions `reader` provides, reading them in chunks.
+    /// Malformed instructions are an [`io::ErrorKind::InvalidData`] error
+    /// holding the [`ParseError`].
+    pub fn read<R: Read>(&mut self, mut reader: R) -> io::Result<()> {
+        let mut chunk = vec![0; 1 << 16];
+        loop {
+            match reader.read(&mut chunk) {
+                Ok(0) => return Ok(()),
+                Ok(count) => self.feed(&chunk[..count]).map_err(|error| io::Error::new(io::ErrorKind::InvalidData, error))?,
+                Err(err) if err.kind() == io::ErrorKind::Interrupted => (),
+                Err(err) => return Err(err),
+            }
+        }
+    }
+
+    /// Floor Santa is on after the instructions so far.
+    pub fn floor(&self) -> i64 {
+        self.floor
+    }
+
+    /// Number of instructions followed so far.
+    pub fn position(&self) -> usize {
+        self.position
+    }
+
+    /// Lowest and highest floor reached so far.
+    pub fn range(&self) -> (i64, i64) {
+        (self.lowest, self.highest)
+    }
+
+    /// Position of the first instruction that took Santa to `floor`, if it
+    /// was watched and has been reached.
+    pub fn first_visit(&self, floor: i64) -> Option<usize> {
+        self.first_visits.get(&floor).copied().flatten()
+    }
+
+    /// Position of the first instruction that took Santa into the basement.
+    pub fn basement_position(&self) -> Option<usize> {
+        self.first_visit(-1)
+    }
+}
+
+// the character `bytes` start with, as far as it is valid UTF-8
+fn first_char(bytes: &[u8]) -> String {
+    let valid = match std::str::from_utf8(bytes) {
+        Ok(text) => text,
+        Err(err) => std::str::from_utf8(&bytes[..err.valid_up_to()]).unwrap(),
+    };
+    valid.chars().next().map_or_else(|| String::from_utf8_lossy(&bytes[..1]).into_owned(), String::from)
+}
+
+fn track(instructions: &str) -> Result<FloorTracker, ParseError> {
+    let mut tracker = FloorTracker::new();
+    tracker.feed(instructions.as_bytes())?;
+    Ok(tracker)
+}
+
+/// Floor Santa ends up on after following all `instructions`.
+pub fn final_floor(instructions: &str) -> Result<i64, ParseError> {
+    track(instructions).map(|tracker| tracker.floor())
+}
+
+/// Position of the first instruction that takes Santa into the basement,
+/// starting at 1.
+pub fn basement_position(instructions: &str) -> Result<Option<usize>, ParseError> {
+    track(instructions).map(|tracker| tracker.basement_position())
+}
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    const DAY: u8 = 1;
+    const TITLE: &'static str = "Not Quite Lisp";
+    type Input = FloorTracker;
+
+    // only the first line holds instructions
+    fn parse(input: &str) -> Result<FloorTracker, ParseError> {
+        track(lines(input).next().map_or("", |line| line.text))
+    }
+
+    fn part1(tracker: &FloorTracker) -> Option<Answer> {
+        Some(tracker.floor().into())
+    }
+
+    fn part2(tracker: &FloorTracker) -> Option<Answer> {
+        tracker.basement_position().map(Answer::from)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tracker() {
+        let mut tracker = FloorTracker::watching([2, 0, -3]);
+        tracker.feed(b"(()").unwrap();
+        tracker.feed(b"))\n)(").unwrap();
+        assert_eq!(-1, tracker.floor());
+        assert_eq!(7, tracker.position());
+        assert_eq!((-2, 2), tracker.range());
+        assert_eq!(Some(5), tracker.basement_position());
+        assert_eq!(Some(2), tracker.first_visit(2));
+        assert_eq!(Some(0), tracker.first_visit(0));
+        assert_eq!(None, tracker.first_visit(-3));
+        assert_eq!(None, tracker.first_visit(1));
+        assert_eq!(Err(ParseError::new(2, 3, EXPECTED, "x")), tracker.feed(b"x"));
+    }
+
+    #[test]
+    fn test_default() {
+        let mut tracker = FloorTracker::default();
+        assert_eq!(FloorTracker::new(), tracker);
+        tracker.feed(b"())\n").unwrap();
+        assert_eq!(Some(3), tracker.basement_position());
+        assert_eq!(Err(ParseError::new(2, 1, EXPECTED, "x")), tracker.feed(b"x"));
+    }
+
+    #[test]
+    fn test_non_ascii() {
+        assert_eq!(Err(ParseError::new(1, 3, EXPECTED, "é")), FloorTracker::new().feed("((é)".as_bytes()));
+        assert_eq!(Err(ParseError::new(1, 2, EXPECTED, "→")), Day01::parse("(→\n"));
+        assert_eq!(Err(ParseError::new(1, 2, EXPECTED, "\u{fffd}")), FloorTracker::new().feed(b"(\xff"));
+    }
+
+    #[test]
+    fn test_read() {
+        // a stream that does not fit into one chunk, and is never held in memory
+        let ups = io::repeat(b'(').take(3_000_000);
+        let downs = io::repeat(b')').take(3_000_001);
+        let mut tracker = FloorTracker::watching([1_000_000]);
+        tracker.read(ups.chain(downs)).unwrap();
+        assert_eq!(-1, tracker.floor());
+        assert_eq!(Some(6_000_001), tracker.basement_position());
+        assert_eq!(Some(1_000_000), tracker.first_visit(1_000_000));
+        assert_eq!((-1, 3_000_000), tracker.range());
+
+        let error = FloorTracker::new().read("(()x".as_bytes()).unwrap_err();
+        assert_eq!(io::ErrorKind::InvalidData, error.kind());
+        assert_eq!("expected '(' or ')', found 'x'", error.to_string());
+    }
+}
@@ -0,0 +1,63 @@
+#![allow(unused)]
+#![allow(dead_code)]
+use aoc_parse::separated;
+use aoc_runner::{lines, Answer, ParseError, Solution};
+
+/// Wrapping paper needed for a present with `dims` sorted ascending.
+pub fn wrapping_paper(dims: &[i64; 3]) -> i64 {
+    2 * (dims[0] * (dims[1] + dims[2]) + dims[1] * dims[2]) + dims[0] * dims[1]
+}
+
+/// Ribbon needed for a present with `dims` sorted ascending.
+pub fn ribbon(dims: &[i64; 3]) -> i64 {
+    2 * (dims[0] + dims[1]) + dims.iter().product::<i64>()
+}
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    const DAY: u8 = 2;
+    const TITLE: &'static str = "I Was Told There Would Be No Math";
+    // dimensions of each present, sorted ascending
+    type Input = Vec<[i64; 3]>;
+
+    fn parse(input: &str) -> Result<Vec<[i64; 3]>, ParseError> {
+        lines(input)
+             .map(|line| {
+                let mut dims = separated(line, line.text, "x", "a dimension")?;
+                dims.sort();
+                Ok(dims)
+             }).collect()
+    }
+
+    fn part1(presents: &Vec<[i64; 3]>) -> Option<Answer> {
+        Some(presents.iter()
+                     .map(wrapping_paper)
+                     .sum::<i64>()
+                     .into())
+    }
+
+    fn part2(presents: &Vec<[i64; 3]>) -> Option<Answer> {
+        Some(presents.iter()
+                     .map(ribbon)
+                     .sum::<i64>()
+                     .into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part1() {
+        let presents = Day02::parse("2x3x4\n1x1x10").unwrap();
+        assert_eq!(Some(101.into()), Day02::part1(&presents));
+    }
+
+    #[test]
+    fn test_parse_error() {
+        assert_eq!(Err(ParseError::new(2, 3, "a dimension", "")), Day02::parse("2x3x4\n1x\n"));
+        assert_eq!(Err(ParseError::new(1, 7, "end of line", "5")), Day02::parse("2x3x4x5"));
+    }
+}
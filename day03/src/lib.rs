@@ -0,0 +1,71 @@
+#![allow(unused)]
+#![allow(dead_code)]
+use aoc_grid::Grid2D;
+use aoc_runner::{lines, Answer, ParseError, Solution};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+struct Position {
+    x: i64,
+    y: i64,
+}
+
+impl Position {
+    fn new(x: i64, y: i64) -> Position {
+        Position{x, y}
+    }
+
+    fn walk(self, dir: char) -> Position {
+        match dir {
+            '^' => Position::new(self.x, self.y - 1),
+            '>' => Position::new(self.x + 1, self.y),
+            '<' => Position::new(self.x - 1, self.y),
+            'v' => Position::new(self.x, self.y + 1),
+            _ => panic!("Unexpected char!")
+        }
+    }
+}
+
+/// Number of houses receiving at least one present when `santas` take
+/// turns following the `directions`.
+pub fn houses_visited(directions: &str, santas: usize) -> usize {
+    let route = |visit: &mut dyn FnMut(Position)| {
+        let mut position = vec![Position{x: 0, y: 0}; santas.max(1)];
+        // every santa delivers a present at the start
+        visit(position[0]);
+        directions.chars().enumerate().for_each(|(i, c)| {
+            let index = i % position.len();
+            position[index] = position[index].walk(c);
+            visit(position[index]);
+        });
+    };
+    // the first trip finds the part of the town to map
+    let (mut min, mut max) = (Position{x: 0, y: 0}, Position{x: 0, y: 0});
+    route(&mut |house| {
+        min = Position::new(min.x.min(house.x), min.y.min(house.y));
+        max = Position::new(max.x.max(house.x), max.y.max(house.y));
+    });
+    let mut visited = Grid2D::new((max.x - min.x + 1) as usize, (max.y - min.y + 1) as usize, false);
+    route(&mut |house| visited[((house.x - min.x) as usize, (house.y - min.y) as usize)] = true);
+    visited.iter().filter(|visited| **visited).count()
+}
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    const DAY: u8 = 3;
+    const TITLE: &'static str = "Perfectly Spherical Houses in a Vacuum";
+    type Input = String;
+
+    fn parse(input: &str) -> Result<String, ParseError> {
+        lines(input).map(|line| line.validate(|c| "^v<>".contains(c), "one of '^', 'v', '<' or '>'"))
+                    .collect()
+    }
+
+    fn part1(directions: &String) -> Option<Answer> {
+        Some(houses_visited(directions, 1).into())
+    }
+
+    fn part2(directions: &String) -> Option<Answer> {
+        Some(houses_visited(directions, 2).into())
+    }
+}
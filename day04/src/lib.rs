@@ -0,0 +1,48 @@
+#![allow(unused)]
+#![allow(dead_code)]
+use aoc_runner::{Answer, ParseError, Solution};
+
+
+fn mine(puzzle_input: &[u8], nonce: usize) -> md5::Digest {
+    let mut input = puzzle_input.to_vec();
+    input.extend(nonce.to_string().as_bytes());
+    md5::compute(&input)
+}
+
+/// Lowest positive nonce for which the hex digest of `secret` followed by the
+/// nonce starts with `zeros` zeros.
+pub fn lowest_nonce(secret: &str, zeros: usize) -> usize {
+    // puzzle input is just a single line, but we need it as bytes
+    let puzzle_input = secret.as_bytes();
+    let full_bytes = zeros / 2;
+    // iterate over all numbers i starting at 1
+    for i in 1.. {
+        let digest = mine(puzzle_input, i);
+        // two hex digits per byte, an odd count also checks the high nibble of the next one
+        if digest[..full_bytes].iter().all(|byte| *byte == 0)
+            && (zeros.is_multiple_of(2) || digest[full_bytes] & 0xf0 == 0) {
+            return i;
+        }
+    }
+    panic!("We should never reach this point");
+}
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    const DAY: u8 = 4;
+    const TITLE: &'static str = "The Ideal Stocking Stuffer";
+    type Input = String;
+
+    fn parse(input: &str) -> Result<String, ParseError> {
+        Ok(input.lines().next().unwrap_or_default().to_string())
+    }
+
+    fn part1(secret: &String) -> Option<Answer> {
+        Some(lowest_nonce(secret, 5).into())
+    }
+
+    fn part2(secret: &String) -> Option<Answer> {
+        Some(lowest_nonce(secret, 6).into())
+    }
+}
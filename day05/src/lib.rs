@@ -0,0 +1,93 @@
+#![allow(unused)]
+#![allow(dead_code)]
+
+use std::{collections::HashSet, fmt::Display};
+
+use aoc_runner::{Answer, ParseError, Solution};
+
+/// Whether `line` is nice by the rules of part 1.
+pub fn is_nice(line: &str) -> bool {
+    // sliding window
+    const FORBIDDEN: [&[u8; 2]; 4] = [b"ab", b"cd", b"pq", b"xy"];
+    let chars = line.as_bytes();
+    let mut vowels: u32 = 0;
+    let mut pair = false;
+    for i in 0..chars.len() {
+        match chars[i] as char {
+            'a' | 'e' | 'i' | 'o' | 'u' => vowels += 1,
+            _ => ()
+        };
+        if i < chars.len() - 1 {
+            pair = pair || chars[i] == chars[i + 1];
+            for forbidden in FORBIDDEN {
+                if *forbidden.as_ref() == chars[i..i + 2] {
+                    return false;
+                }
+            }
+        }
+    }
+    vowels >= 3 && pair
+}
+
+/// Whether `line` is nice by the improved rules of part 2.
+pub fn is_nice_improved(line: &str) -> bool {
+    use std::collections::HashMap;
+    // this is rather ugly, but it at least it's relatively efficient
+    let chars: Vec<char> = line.as_bytes().iter().map(|c| *c as char).collect();
+
+    // keep track of char tuples we found
+    let mut tuples: HashMap<&[char], usize> = HashMap::new();
+
+    // nice conditions
+    let mut repeats = false;
+    let mut doublepair = false;
+
+    for i in 0..chars.len().saturating_sub(1) {
+
+        // check for pattern aba
+        if !repeats && i < chars.len() - 2 && chars[i] == chars[i + 2] {
+            repeats = true;
+        }
+
+        if !doublepair {
+            // check if current pair exists somewhere else
+            if let Some(pos) = tuples.get(&chars[i..i + 2]) {
+
+                // check if non-overlapping
+                if *pos < i - 1 {
+                    doublepair = true;
+                }
+            } else {
+                // we only update if the key is not present,
+                // because the smallest pos value is always the relevant one
+                tuples.insert(&chars[i..i + 2], i);
+            }
+        }
+
+        if repeats && doublepair {
+            // exit early as there are not conditions which could 'unnice' us
+            return true;
+        }
+    }
+    false
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    const DAY: u8 = 5;
+    const TITLE: &'static str = "Doesn't He Have Intern-Elves For This?";
+    type Input = Vec<String>;
+
+    fn parse(input: &str) -> Result<Vec<String>, ParseError> {
+        Ok(input.lines().map(String::from).collect())
+    }
+
+    fn part1(lines: &Vec<String>) -> Option<Answer> {
+        Some(lines.iter().filter(|line| is_nice(line)).count().into())
+    }
+
+    fn part2(lines: &Vec<String>) -> Option<Answer> {
+        Some(lines.iter().filter(|line| is_nice_improved(line)).count().into())
+    }
+}
@@ -0,0 +1,112 @@
+#![allow(unused)]
+#![allow(dead_code)]
+
+use std::sync::LazyLock;
+
+use aoc_grid::Grid2D;
+use aoc_parse::{parse_any, Record, Template};
+use aoc_runner::{lines, Answer, Line, ParseError, Solution};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Action {
+    TurnOn,
+    TurnOff,
+    Toggle
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Instruction {
+    pub action: Action,
+    pub x1: usize,
+    pub y1: usize,
+    pub x2: usize,
+    pub y2: usize
+}
+
+/// Width and height of the grid of lights.
+pub const SIZE: usize = 1000;
+
+// in the order of the actions
+static TEMPLATES: LazyLock<[Template; 3]> = LazyLock::new(|| {
+    let corners = "{int:a coordinate},{int:a coordinate} through {int:a coordinate},{int:a coordinate}";
+    ["turn on", "turn off", "toggle"].map(|action| Template::new(&format!("{} {}", action, corners)))
+});
+
+fn coordinate(record: &Record, field: usize) -> Result<usize, ParseError> {
+    match record.get::<usize>(field) {
+        Ok(value) if value < SIZE => Ok(value),
+        _ => Err(record.error(field, "a coordinate below 1000")),
+    }
+}
+
+impl Instruction {
+    /// Parse a line like "turn on 0,0 through 999,999".
+    pub fn parse(line: Line) -> Result<Instruction, ParseError> {
+        let (index, record) = parse_any(&*TEMPLATES, line)?;
+        let action = [Action::TurnOn, Action::TurnOff, Action::Toggle][index];
+        let (x1, y1) = (coordinate(&record, 0)?, coordinate(&record, 1)?);
+        let (x2, y2) = (coordinate(&record, 2)?, coordinate(&record, 3)?);
+        // the first corner is the top left one
+        if x2 < x1 {
+            return Err(record.error(2, &format!("a coordinate of at least {}", x1)));
+        }
+        if y2 < y1 {
+            return Err(record.error(3, &format!("a coordinate of at least {}", y1)));
+        }
+        Ok(Instruction{action, x1, y1, x2, y2})
+    }
+}
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    const DAY: u8 = 6;
+    const TITLE: &'static str = "Probably a Fire Hazard";
+    type Input = Vec<Instruction>;
+
+    fn parse(input: &str) -> Result<Vec<Instruction>, ParseError> {
+        lines(input).map(Instruction::parse).collect()
+    }
+
+    fn part1(instructions: &Vec<Instruction>) -> Option<Answer> {
+        let mut grid = Grid2D::new(SIZE, SIZE, false);
+        for instruction in instructions {
+            for row in grid.region_mut(instruction.x1..=instruction.x2, instruction.y1..=instruction.y2) {
+                match instruction.action {
+                    Action::TurnOn => row.fill(true),
+                    Action::TurnOff => row.fill(false),
+                    Action::Toggle => row.iter_mut().for_each(|light| *light = !*light),
+                }
+            }
+        }
+        Some(grid.iter().filter(|light| **light).count().into())
+    }
+
+    fn part2(instructions: &Vec<Instruction>) -> Option<Answer> {
+        let mut grid = Grid2D::new(SIZE, SIZE, 0i64);
+        for instruction in instructions {
+            let change = match instruction.action {
+                Action::TurnOn => 1,
+                Action::TurnOff => -1,
+                Action::Toggle => 2,
+            };
+            for row in grid.region_mut(instruction.x1..=instruction.x2, instruction.y1..=instruction.y2) {
+                // brightness is clamped to 0
+                row.iter_mut().for_each(|brightness| *brightness = (*brightness + change).max(0));
+            }
+        }
+        Some(grid.iter().sum::<i64>().into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_error() {
+        assert_eq!(Err(ParseError::new(2, 21, "a coordinate of at least 5", "4")), Day06::parse("toggle 0,0 through 1,1\nturn on 5,5 through 4,9").map(|_| ()));
+        assert_eq!(Err(ParseError::new(1, 24, "a coordinate of at least 2", "1")), Day06::parse("turn off 0,2 through 0,1").map(|_| ()));
+        assert_eq!(Err(ParseError::new(1, 20, "a coordinate below 1000", "1000")), Day06::parse("toggle 0,0 through 1000,0").map(|_| ()));
+    }
+}
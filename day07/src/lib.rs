@@ -0,0 +1,273 @@
+#![allow(unused)]
+#![allow(dead_code)]
+
+use std::{hash::{Hash, Hasher}, borrow::BorrowMut, ops::DerefMut};
+use std::rc::Rc;
+use std::cell::RefCell;
+use std::collections::{HashMap, HashSet};
+
+use aoc_runner::{lines, Answer, Line, ParseError, Solution};
+
+trait Evaluate {
+    fn evaluate(&mut self) -> u16;
+}
+
+#[derive(Debug, Clone)]
+enum Operator {
+    And,
+    Or,
+    LShift,
+    RShift,
+    Not,
+    Const(u16),
+    Passthrough
+}
+
+impl Operator {
+    fn new(op: &str) -> Operator {
+        match op {
+            "AND" => Operator::And,
+            "OR" => Operator::Or,
+            "LSHIFT" => Operator::LShift,
+            "RSHIFT" => Operator::RShift,
+            "NOT" => Operator::Not,
+            _ => panic!("Unknown operator {}", op)
+        }
+    }
+    fn new_const(value: u16) -> Operator {
+        Operator::Const(value)
+    }
+}
+
+// Wire has two inputs: left and right
+// both have to implement Evaluate
+// left and right are either None, or a smartpointer to another Wire
+#[derive(Debug, Clone)]
+struct Wire {
+    name: String,
+    left: Option<Rc<RefCell<Wire>>>,
+    right: Option<Rc<RefCell<Wire>>>,
+    operator: Operator,
+    value: Option<u16>
+}
+
+impl Hash for Wire {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.name.hash(state);
+    }
+}
+
+impl Wire {
+    fn new(name: &str, operator: Operator) -> Wire {
+        Wire{name: String::from(name), left: None, right: None, operator, value: None}
+    }
+
+    fn new_const(identifier: &str) -> Option<Wire> {
+        match identifier.parse() {
+            Ok(value) => Some(Wire{name: String::new(), operator: Operator::Const(value), left: None, right: None, value: None}),
+            Err(_) => None
+        }
+    }
+    
+    fn new_const_u16(value: u16) -> Wire {
+        Wire{name: String::new(), operator: Operator::Const(value), left: None, right: None, value: None}
+    }
+}
+
+impl Evaluate for Wire {
+    // evaluate the wire and cache the result in wire (needs to be mutable)
+    fn evaluate(&mut self) -> u16 {
+        // evaluate the wire, make sure that left is mutable
+        if let Some(result) = self.value {
+            return result;
+        };
+        let lefteval = match self.left {
+            Some(ref left) => left.as_ref().borrow_mut().evaluate(),
+            None => 0u16
+        };
+        let righteval = match self.right {
+            Some(ref right) => right.as_ref().borrow_mut().evaluate(),
+            None => 0u16
+        };
+        let result = match self.operator {
+            Operator::Const(value) => value,
+            Operator::And => lefteval & righteval,
+            Operator::Or => lefteval | righteval,
+            Operator::LShift => lefteval << righteval,
+            Operator::RShift => lefteval >> righteval,
+            Operator::Not => !lefteval,
+            Operator::Passthrough => lefteval
+        };
+        self.value = Some(result);
+        result
+    }
+}
+
+fn is_wire(name: &str) -> bool {
+    !name.is_empty() && name.chars().all(|c| c.is_ascii_lowercase())
+}
+
+// a signal is either a constant or a wire, whose name is returned
+fn check_signal<'a>(line: Line, token: &'a str) -> Result<Option<&'a str>, ParseError> {
+    match token.parse::<u16>() {
+        Ok(_) => Ok(None),
+        Err(_) if is_wire(token) => Ok(Some(token)),
+        Err(_) => Err(line.error(token, "a wire or a signal")),
+    }
+}
+
+// check the syntax of a gate, returning the wires it reads
+fn check_gate(line: Line<'_>) -> Result<Vec<&str>, ParseError> {
+    let (inputs, output) = line.split_once(line.text, " -> ")?;
+    if !is_wire(output) {
+        return Err(line.error(output, "a wire name"));
+    }
+    let tokens: Vec<&str> = inputs.split(' ').collect();
+    let signals = match tokens[..] {
+        [signal] | ["NOT", signal] => vec![signal],
+        [left, operator, right] => {
+            if !["AND", "OR", "LSHIFT", "RSHIFT"].contains(&operator) {
+                return Err(line.error(operator, "'AND', 'OR', 'LSHIFT' or 'RSHIFT'"));
+            }
+            vec![left, right]
+        },
+        [first, _] => return Err(line.error(first, "'NOT'")),
+        _ => return Err(line.error(tokens[3], "'->'")),
+    };
+    let mut read = Vec::new();
+    for signal in signals {
+        read.extend(check_signal(line, signal)?);
+    }
+    Ok(read)
+}
+
+fn buildgraph(lines: &[String]) -> HashMap<String, Rc<RefCell<Wire>>> {
+    let mut wires: HashMap<String, Rc<RefCell<Wire>>> = HashMap::new();
+    for line in lines {
+        let parts: Vec<&str> = line.split(" -> ").collect();
+        let wire_name = parts[1];
+        let inputs: Vec<&str> = parts[0].split(' ').collect();
+        match inputs.len() {
+            1 => {
+                let left = Wire::new_const(inputs[0]).map(|wire| Rc::new(RefCell::new(wire)));
+                let mut gate = Wire::new(wire_name, Operator::Passthrough);
+                gate.left = left;
+                wires.insert(String::from(wire_name), Rc::new(RefCell::new(gate)));
+            },
+            2 => {
+                let left = Wire::new_const(inputs[1]).map(|wire| Rc::new(RefCell::new(wire)));
+                let mut gate = Wire::new(wire_name, Operator::new(inputs[0]));
+                gate.left = left;
+                wires.insert(String::from(wire_name), Rc::new(RefCell::new(gate)));
+            },
+            3 => {
+                let left = Wire::new_const(inputs[0]).map(|wire| Rc::new(RefCell::new(wire)));
+                let right = Wire::new_const(inputs[2]).map(|wire| Rc::new(RefCell::new(wire)));
+                let mut gate = Wire::new(wire_name, Operator::new(inputs[1]));
+                gate.left = left;
+                gate.right = right;
+                wires.insert(String::from(wire_name), Rc::new(RefCell::new(gate)));
+            }
+            _ => panic!("Invalid input")
+        }
+    }
+
+    // due to our nasty buildup, we still need to actually connect the wires (painful)
+    for line in lines {
+        let parts: Vec<&str> = line.split(" -> ").collect();
+        let wire_name = parts[1];
+        let inputs: Vec<&str> = parts[0].split(' ').collect();
+        let thiswire: Rc<RefCell<Wire>> = wires.get(wire_name).unwrap().clone();
+        match inputs.len() {
+            1 => {
+                // only necessary if inputs[0] is not a number, for this check if all characters are digits
+                if !inputs[0].chars().all(char::is_numeric) {
+                    let left = wires.get(inputs[0]).unwrap().clone();
+                    thiswire.as_ref().borrow_mut().left = Some(left);
+                }
+            },
+            2 => {
+                if let Some(left) = wires.get(inputs[1]) {
+                    thiswire.as_ref().borrow_mut().left = Some(left.clone());
+                }
+            },
+            3 => {
+                if let Some(left) = wires.get(inputs[0]) {
+                    thiswire.as_ref().borrow_mut().left = Some(left.clone());
+                };
+                if let Some(right) = wires.get(inputs[2]) {
+                    thiswire.as_ref().borrow_mut().right = Some(right.clone());
+                };
+            }
+            _ => panic!("Invalid input")
+        }
+    }
+    wires
+}
+
+/// Signal on `wire` of the circuit described by `gates`, after replacing the
+/// gates driving the wires in `overrides` with constant signals.
+pub fn signal(gates: &[String], wire: &str, overrides: &[(&str, u16)]) -> Option<u16> {
+    let wires = buildgraph(gates);
+    for (name, value) in overrides {
+        let mut overridden = wires.get(*name)?.as_ref().borrow_mut();
+        overridden.operator = Operator::new_const(*value);
+        overridden.left = None;
+        overridden.right = None;
+    }
+    let result = wires.get(wire)?.as_ref().borrow_mut().evaluate();
+    Some(result)
+}
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    const DAY: u8 = 7;
+    const TITLE: &'static str = "Some Assembly Required";
+    // the circuit is rebuilt by each part, as part 2 rewires it
+    type Input = Vec<String>;
+
+    fn parse(input: &str) -> Result<Vec<String>, ParseError> {
+        let mut reads = Vec::new();
+        let mut outputs = HashSet::new();
+        for line in lines(input) {
+            reads.push((line, check_gate(line)?));
+            outputs.insert(line.text.rsplit(" -> ").next().unwrap_or_default());
+        }
+        for (line, wires) in &reads {
+            if let Some(wire) = wires.iter().find(|wire| !outputs.contains(*wire)) {
+                return Err(line.error(wire, "a wire that is connected to a signal"));
+            }
+        }
+        Ok(input.lines().map(String::from).collect())
+    }
+
+    fn part1(lines: &Vec<String>) -> Option<Answer> {
+        Some(signal(lines, "a", &[])?.into())
+    }
+
+    fn part2(lines: &Vec<String>) -> Option<Answer> {
+        // Since we allow running the parts independendly, we evaluate the part1 circuit again
+        let part1result = signal(lines, "a", &[])?;
+        Some(signal(lines, "a", &[("b", part1result)])?.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    static TESTINPUT: &str = "123 -> x\n456 -> y\nx AND y -> d\nx OR y -> e\nx LSHIFT 2 -> f\ny RSHIFT 2 -> g\nNOT x -> h\nNOT y -> a";
+
+    #[test]
+    fn test_part1() {
+        let lines = Day07::parse(TESTINPUT).unwrap();
+        assert_eq!(Some(65079.into()), Day07::part1(&lines));
+    }
+
+    #[test]
+    fn test_parse_error() {
+        assert_eq!(Err(ParseError::new(2, 3, "'AND', 'OR', 'LSHIFT' or 'RSHIFT'", "XOR")), Day07::parse("1 -> x\nx XOR x -> a"));
+        assert_eq!(Err(ParseError::new(1, 5, "a wire that is connected to a signal", "y")), Day07::parse("NOT y -> a"));
+        assert_eq!(Err(ParseError::new(1, 5, "' -> '", "")), Day07::parse("1 ->"));
+    }
+}
@@ -0,0 +1,173 @@
+#![allow(unused)]
+#![allow(dead_code)]
+use aoc_runner::{lines, Answer, Line, ParseError, Solution};
+
+/// Length of `line` after encoding it as a string literal, quotes included.
+pub fn get_num_chars_reencode(line: &str) -> usize {
+    2 + line.chars().map(|c|
+            match c {
+                '\\' | '\"' => 2,
+                _    => 1
+            }
+    )
+    .sum::<usize>()
+}
+
+/// Number of characters the string literal `line` decodes to.
+pub fn get_num_chars(line: &str) -> usize {
+    let mut escaped = false;
+    let result = line.chars().map(|c|
+        match c {
+            '\\' => {
+                escaped = !escaped;
+                if escaped { 0 } else { 1 }
+            },
+            'x' => {
+                let originalescaped = escaped;
+                escaped = false;
+                if originalescaped { - 1 } else { 1 } 
+            },
+            _ => {
+                escaped = false;
+                1
+            }
+        
+        }
+    ).sum::<i64>();
+    result as usize - 2
+}
+
+// the characters between the quotes, which get_num_chars relies on
+fn validate_literal(line: &Line) -> Result<(), ParseError> {
+    let body = &line.text[1..line.text.len() - 1];
+    let mut chars = body.char_indices();
+    while let Some((i, c)) = chars.next() {
+        match c {
+            '"' => return Err(line.error(&body[i..i + 1], "'\\' before '\"'")),
+            '\\' => match chars.next() {
+                Some((_, '\\' | '"')) => (),
+                Some((j, 'x')) => {
+                    let end = body[j + 1..].char_indices().nth(2).map_or(body.len(), |(k, _)| j + 1 + k);
+                    let digits = &body[j + 1..end];
+                    if digits.len() != 2 || !digits.chars().all(|c| c.is_ascii_hexdigit()) {
+                        return Err(line.error(digits, "two hex digits"));
+                    }
+                    chars.nth(1);
+                },
+                Some((j, other)) => return Err(line.error(&body[j..j + other.len_utf8()], "'\\', '\"' or 'x' after '\\'")),
+                // the backslash escapes the closing quote
+                None => return Err(line.error_at_end("'\"'")),
+            },
+            c if !c.is_ascii() || c.is_ascii_control() => return Err(line.error(&body[i..i + c.len_utf8()], "a printable ASCII character")),
+            _ => (),
+        }
+    }
+    Ok(())
+}
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    const DAY: u8 = 8;
+    const TITLE: &'static str = "Matchsticks";
+    type Input = Vec<String>;
+
+    fn parse(input: &str) -> Result<Vec<String>, ParseError> {
+        lines(input).map(|line| {
+            if !line.text.starts_with('"') {
+                return Err(line.error(&line.text[..line.text.len().min(1)], "'\"'"));
+            }
+            if line.text.len() < 2 || !line.text.ends_with('"') {
+                return Err(line.error_at_end("'\"'"));
+            }
+            validate_literal(&line)?;
+            Ok(line.text.to_string())
+        }).collect()
+    }
+
+    fn part1(lines: &Vec<String>) -> Option<Answer> {
+        Some(lines.iter().map(|line| line.len() - get_num_chars(line)).sum::<usize>().into())
+    }
+
+    fn part2(lines: &Vec<String>) -> Option<Answer> {
+        Some(lines.iter().map(|line| get_num_chars_reencode(line) - line.len()).sum::<usize>().into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // static array of test strings
+    static TEST_STRINGS: [&str; 4] = ["\"\"", "\"abc\"", "\"aaa\\\"aaa\"", "\"\\x27\""];
+
+    #[test]
+    fn num_chars_empty() {
+        assert_eq!(0, get_num_chars(TEST_STRINGS[0]));
+    }
+
+    #[test]
+    fn num_chars_simple() {
+        assert_eq!(3, get_num_chars(TEST_STRINGS[1]));
+    }
+
+    #[test]
+    fn num_chars_singleescaped() {
+        assert_eq!(7, get_num_chars(TEST_STRINGS[2]));
+    }
+
+    #[test]
+    fn num_chars_hexcode() {
+        assert_eq!(1, get_num_chars(TEST_STRINGS[3]));
+    }
+
+    #[test]
+    fn num_chars_empty_reencode() {
+        assert_eq!(6, get_num_chars_reencode(TEST_STRINGS[0]));
+    }
+
+    #[test]
+    fn num_chars_simple_reencode() {
+        assert_eq!(9, get_num_chars_reencode(TEST_STRINGS[1]));
+    }
+
+    #[test]
+    fn num_chars_singleescaped_reencode() {
+        assert_eq!(16, get_num_chars_reencode(TEST_STRINGS[2]));
+    }
+
+    #[test]
+    fn num_chars_hexcode_reencode() {
+        assert_eq!(11, get_num_chars_reencode(TEST_STRINGS[3]));
+    }
+    #[test]
+    fn num_chars_doubleescape() {
+        assert_eq!(6, get_num_chars("\"\\\\hello\""));
+    }
+
+    #[test]
+    fn num_chars_doubleescape_reencode() {
+        assert_eq!(4 + 4 + 5 + 2, get_num_chars_reencode("\"\\\\hello\""));
+    }
+
+    #[test]
+    fn test_parse_error() {
+        assert_eq!(Err(ParseError::new(2, 4, "two hex digits", "4")), Day08::parse("\"\"\n\"\\x4\""));
+        assert_eq!(Err(ParseError::new(1, 4, "two hex digits", "4g")), Day08::parse("\"\\x4g\""));
+        assert_eq!(Err(ParseError::new(1, 3, "'\\', '\"' or 'x' after '\\'", "n")), Day08::parse("\"\\n\""));
+        assert_eq!(Err(ParseError::new(1, 3, "'\\' before '\"'", "\"")), Day08::parse("\"a\"b\""));
+        assert_eq!(Err(ParseError::new(1, 7, "'\"'", "")), Day08::parse("\"abc\\\""));
+    }
+
+    #[test]
+    fn part1_testinput() {
+        let testinput = Day08::parse(&TEST_STRINGS.join("\n")).unwrap();
+        assert_eq!(Some(12.into()), Day08::part1(&testinput));
+    }
+
+    #[test]
+    fn part2_testinput() {
+        let testinput = Day08::parse(&TEST_STRINGS.join("\n")).unwrap();
+        assert_eq!(Some(19.into()), Day08::part2(&testinput));
+    }
+}
@@ -0,0 +1,121 @@
+#![allow(unused)]
+#![allow(dead_code)]
+
+use aoc_graph::{held_karp, Goal, Graph, Route, Shape, HELD_KARP_LIMIT};
+use aoc_parse::Template;
+use aoc_runner::{lines, trace, Answer, ParseError, Solution};
+
+/// Most locations in the input, so that `best_route` can use Held-Karp.
+pub const MAX_LOCATIONS: usize = HELD_KARP_LIMIT;
+
+/// Parse lines like "London to Dublin = 464", with at most `MAX_LOCATIONS`
+/// locations.
+pub fn parse_distances(input: &str) -> Result<Graph<usize>, ParseError> {
+    let template = Template::new("{name:a location} to {name:a location} = {int:a distance}");
+    let mut graph = Graph::new();
+    for line in lines(input) {
+        let record = template.parse(line)?;
+        let source = graph.intern(record.text(0));
+        let dest = graph.intern(record.text(1));
+        if graph.len() > MAX_LOCATIONS {
+            let field = if source == MAX_LOCATIONS { 0 } else { 1 };
+            return Err(record.error(field, &format!("one of the first {} locations", MAX_LOCATIONS)));
+        }
+        graph.connect(source, dest, record.get(2)?);
+    }
+    Ok(graph)
+}
+
+/// The shortest or longest route visiting every location once.
+pub fn best_route(graph: &Graph<usize>, goal: Goal) -> Option<Route<usize>> {
+    held_karp(graph, Shape::Path, goal)
+}
+
+// the length of the best route, explaining the route leg by leg
+fn route_length(graph: &Graph<usize>, goal: Goal) -> Option<Answer> {
+    let route = best_route(graph, goal)?;
+    // without locations there is no route to explain
+    if route.nodes.is_empty() {
+        return Some(route.cost.into());
+    }
+    trace("route", || {
+        let names = graph.names(&route);
+        let legs = route.nodes.windows(2).map(|leg| graph.weight(leg[0], leg[1]).unwrap());
+        names[1..].iter().zip(legs).fold(names[0].to_string(), |route, (name, distance)| format!("{} -{}-> {}", route, distance, name))
+    });
+    Some(route.cost.into())
+}
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    const DAY: u8 = 9;
+    const TITLE: &'static str = "All in a Single Night";
+    type Input = Graph<usize>;
+
+    fn parse(input: &str) -> Result<Graph<usize>, ParseError> {
+        parse_distances(input)
+    }
+
+    fn part1(graph: &Graph<usize>) -> Option<Answer> {
+        route_length(graph, Goal::Min)
+    }
+
+    fn part2(graph: &Graph<usize>) -> Option<Answer> {
+        route_length(graph, Goal::Max)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    static TESTINPUT: &str = "London to Dublin = 464\nLondon to Belfast = 518\nDublin to Belfast = 141";
+
+    #[test]
+    fn test_part1() {
+        let graph = Day09::parse(TESTINPUT).unwrap();
+        assert_eq!(Some(605.into()), Day09::part1(&graph));
+    }
+
+    #[test]
+    fn test_part2() {
+        let graph = Day09::parse(TESTINPUT).unwrap();
+        assert_eq!(Some(982.into()), Day09::part2(&graph));
+    }
+
+    #[test]
+    fn test_best_route() {
+        let graph = Day09::parse(TESTINPUT).unwrap();
+        let route = best_route(&graph, Goal::Max).unwrap();
+        assert_eq!(982, route.cost);
+        // London has to be in the middle, either way around
+        assert_eq!("London", graph.names(&route)[1]);
+    }
+
+    #[test]
+    fn test_explain() {
+        let graph = Day09::parse(TESTINPUT).unwrap();
+        let (answer, events) = aoc_runner::collect(true, || Day09::part1(&graph));
+        assert_eq!(Some(605.into()), answer);
+        assert_eq!("route: Belfast -141-> Dublin -464-> London", events[0].to_string());
+    }
+
+    #[test]
+    fn test_explain_empty() {
+        let graph = Day09::parse("").unwrap();
+        for part in [Day09::part1, Day09::part2] {
+            let (answer, events) = aoc_runner::collect(true, || part(&graph));
+            assert_eq!(Some(0.into()), answer);
+            assert!(events.is_empty());
+        }
+    }
+
+    #[test]
+    fn test_parse_error() {
+        assert_eq!(Err(ParseError::new(2, 21, "a distance", "far")), Day09::parse("London to Dublin = 464\nLondon to Belfast = far").map(|_| ()));
+        assert_eq!(Err(ParseError::new(1, 8, "'to'", "from")), Day09::parse("London from Dublin = 464").map(|_| ()));
+        let chain: Vec<String> = (0..MAX_LOCATIONS).map(|n| format!("L{} to L{} = 1", n, n + 1)).collect();
+        let expected = format!("one of the first {} locations", MAX_LOCATIONS);
+        assert_eq!(Err(ParseError::new(16, 8, &expected, "L16")), Day09::parse(&chain.join("\n")).map(|_| ()));
+    }
+}
@@ -0,0 +1,76 @@
+#![allow(unused)]
+#![allow(dead_code)]
+use aoc_runner::{lines, Answer, Line, ParseError, Solution};
+
+/// Length of the result of applying `rounds` rounds of look-and-say to `num`.
+pub fn look_and_say(num: &str, rounds: usize) -> usize {
+    // convert input str to vector of i8 (each char is a digit)
+    let mut current: Vec<i8> = num.chars().map(|c| c.to_digit(10).unwrap() as i8).collect();
+    for _ in (0..rounds) {
+        current.push(10); // guard, spares us from handling the end separately
+        let mut newnum: Vec<i8> = Vec::new();
+        assert_ne!(current.len(), 0);
+        let mut digit = current[0];
+        let mut digitcount = 1;
+        for next_digit in &current[1..current.len()] {
+            if *next_digit == digit {
+                digitcount += 1;
+            } else {
+                newnum.push(digitcount);
+                newnum.push(digit);
+                digit = *next_digit;
+                digitcount = 1;
+            }
+        }
+        current = newnum;
+    }
+    current.len()
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u8 = 10;
+    const TITLE: &'static str = "Elves Look, Elves Say";
+    type Input = String;
+
+    fn parse(input: &str) -> Result<String, ParseError> {
+        let line = lines(input).next().unwrap_or(Line { number: 1, text: "" });
+        if line.text.is_empty() {
+            return Err(line.error_at_end("a digit"));
+        }
+        Ok(line.validate(|c| c.is_ascii_digit(), "a digit")?.to_string())
+    }
+
+    fn part1(num: &String) -> Option<Answer> {
+        Some(look_and_say(num, 40).into())
+    }
+
+    fn part2(num: &String) -> Option<Answer> {
+        Some(look_and_say(num, 50).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    static TESTINPUT: &str = "1113122113";
+
+
+    #[test]
+    fn test_lookandsay_3rounds() {
+        assert_eq!(6, look_and_say(&1.to_string(), 5));
+    }
+
+    #[test]
+    fn test_part1_1() {
+        let num = Day10::parse(TESTINPUT).unwrap();
+        assert_eq!(Some(360154.into()), Day10::part1(&num));
+    }
+
+    #[test]
+    fn test_part2() {
+        let num = Day10::parse(TESTINPUT).unwrap();
+        assert_eq!(Some(5103798.into()), Day10::part2(&num));
+    }
+}
@@ -0,0 +1,195 @@
+#![allow(unused)]
+#![allow(dead_code)]
+use aoc_runner::{lines, Answer, Line, ParseError, Solution};
+
+fn encodepw(pw: &str) -> Vec<u8> {
+    pw.bytes().collect()
+}
+
+fn decodepw(pw: &[u8]) -> String {
+    pw.iter().map(|c| *c as char).collect()
+}
+
+static DISALLOWED: [u8; 3] = [b'i', b'o', b'l'];
+fn pwvalid(pw: &[u8]) -> bool {
+    let mut streak = 0;
+    let mut pairs = [0u8; 2];
+    let mut lastchar = 0;
+    for c in pw {
+        if DISALLOWED.iter().any(|d| d == c) {
+            return false;
+        }
+        if streak < 2 {
+            streak = if lastchar + 1 == *c { streak + 1} else { 0 };
+        }
+
+        if pairs[1] == 0 && lastchar == *c {
+            if pairs[0] == 0 {
+                pairs[0] = *c;
+            } else if pairs[0] != *c {
+                pairs[1] = *c;
+            }
+        }
+        lastchar = *c;
+    }
+    pairs[1] > 0 && streak >= 2
+}
+
+// false once every letter was 'z', the password length is fixed
+fn iterate(pw: &mut[u8]) -> bool {
+    const MAXCHAR: u8 = b'z';
+    const MINCHAR: u8 = b'a';
+    // skipahead
+    if let Some(i) = pw.iter().position(|c| DISALLOWED.contains(c)) {
+        pw[i] += 1;
+        pw[i + 1..].fill(MINCHAR);
+        return true;
+    }
+    for i in (0..pw.len()).rev() {
+        if pw[i] < MAXCHAR {
+            pw[i] += 1;
+            return true;
+        }
+        pw[i] = MINCHAR;
+    }
+    false
+}
+
+/// The last valid password with `length` letters, there is none below 5.
+pub fn last_password(length: usize) -> Option<String> {
+    match length {
+        0..=4 => None,
+        // both pairs overlap the straight
+        5 => Some(String::from("xxyzz")),
+        _ => Some("z".repeat(length - 4) + "xxyz"),
+    }
+}
+
+/// The next valid password after `password`, if there is one of the same length.
+pub fn next_password(password: &str) -> Option<String> {
+    let mut current = encodepw(password);
+    if !iterate(&mut current) {
+        return None;
+    }
+    while !pwvalid(&current) {
+        if !iterate(&mut current) {
+            return None;
+        }
+    }
+    Some(decodepw(&current))
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const DAY: u8 = 11;
+    const TITLE: &'static str = "Corporate Policy";
+    type Input = String;
+
+    fn parse(input: &str) -> Result<String, ParseError> {
+        let line = lines(input).next().unwrap_or(Line { number: 1, text: "" });
+        let password = line.validate(|c| c.is_ascii_lowercase(), "a lowercase letter")?;
+        // shorter passwords cannot hold a straight and two pairs
+        if password.len() < 5 {
+            return Err(line.error_at_end("a lowercase letter, passwords have at least 5"));
+        }
+        let last = last_password(password.len()).unwrap();
+        if *password >= *last {
+            return Err(line.error(password, &format!("a password before '{}', the last valid one", last)));
+        }
+        Ok(password.to_string())
+    }
+
+    fn part1(password: &String) -> Option<Answer> {
+        next_password(password).map(Answer::from)
+    }
+
+    fn part2(password: &String) -> Option<Answer> {
+        next_password(password).and_then(|password| next_password(&password)).map(Answer::from)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    static TESTINPUT: &str = "abcdefgh";
+
+    #[test]
+    fn test_iterate() {
+        let mut pw = encodepw("bzy");
+        assert!(iterate(&mut pw));
+        assert_eq!(&pw, &encodepw("bzz"));
+        assert!(iterate(&mut pw));
+        assert_eq!(&pw, &encodepw("caa"));
+        assert!(iterate(&mut pw));
+        assert_eq!(&pw, &encodepw("cab"));
+    }
+
+    #[test]
+    fn test_iterate_skipahead() {
+        let mut pw = encodepw("hihfgas");
+        assert!(iterate(&mut pw));
+        assert_eq!(decodepw(&pw), "hjaaaaa");
+    }
+
+    #[test]
+    fn test_encode_decode() {
+        let pass = "helloworld";
+        assert_eq!(pass, decodepw(&encodepw(pass)));
+    }
+
+    #[test]
+    fn test_invalid_containsi() {
+        assert!(!pwvalid(&encodepw("hijklmmn")));
+    }
+
+    #[test]
+    fn test_invalid_noincrease() {
+        assert!(!pwvalid(&encodepw("abbceffg")));
+    }
+
+    #[test]
+    fn test_invalid_nosecondpair() {
+        assert!(!pwvalid(&encodepw("abbcegjk")));
+    }
+
+    #[test]
+    fn test_valid() {
+        assert!(pwvalid(&encodepw("abcdffaa")));
+    }
+
+    #[test]
+    fn test_part1() {
+        let password = Day11::parse(TESTINPUT).unwrap();
+        assert_eq!(Some("abcdffaa".into()), Day11::part1(&password));
+    }
+
+    #[test]
+    fn test_part1_longskip() {
+        let password = Day11::parse("ghijklmn").unwrap();
+        let expected = "ghjaabcc";
+        assert!(pwvalid(&encodepw(expected)));
+        assert_eq!(expected, Day11::part1(&password).unwrap().to_string());
+    }
+
+    #[test]
+    fn test_last_password() {
+        assert_eq!(None, next_password("zzzzxxyz"));
+        assert_eq!(Some(String::from("zzzzxxyz")), next_password("zzzzxxyy"));
+        assert_eq!(None, next_password("xxyzz"));
+        assert_eq!(None, last_password(4));
+    }
+
+    #[test]
+    fn test_parse_error() {
+        assert_eq!(Err(ParseError::new(1, 2, "a lowercase letter, passwords have at least 5", "")), Day11::parse("a"));
+        assert_eq!(Err(ParseError::new(1, 1, "a password before 'zzzzxxyz', the last valid one", "zzzzzzzz")), Day11::parse("zzzzzzzz"));
+        assert_eq!(Err(ParseError::new(1, 3, "a lowercase letter", "C")), Day11::parse("abCde"));
+    }
+
+    #[test]
+    fn test_part2() {
+        let password = Day11::parse(TESTINPUT).unwrap();
+        assert_eq!(Some("abcdffbb".into()), Day11::part2(&password));
+    }
+}
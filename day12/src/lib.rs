@@ -0,0 +1,198 @@
+#![allow(unused)]
+#![allow(dead_code)]
+
+use std::collections::HashMap;
+
+use aoc_runner::{lines, Answer, ParseError, Solution};
+use serde_json::{self, Value, Map};
+
+fn sum_numbers_json(node: &Value) -> i64 {
+    // recursively parse the nodes and sum up the results
+    if node.is_i64() {
+        return node.as_i64().unwrap();
+    } else if node.is_object() {
+        return sum_numbers_object(node.as_object().unwrap());
+    } else if node.is_array() {
+        return node.as_array().unwrap().iter().map(sum_numbers_json).sum::<i64>()
+    }
+    0
+}
+
+fn sum_numbers_object(object: &Map<String, Value>) -> i64 {
+    if object.values().any(|v| v.as_str() == Some("red")) {
+        return 0;
+    }
+    object.values().map(sum_numbers_json).sum::<i64>()
+}
+
+fn json_error(document: &str, err: serde_json::Error) -> ParseError {
+    let text = document.lines().nth(err.line().saturating_sub(1)).unwrap_or_default();
+    let column = err.column().max(1);
+    let found: String = text.chars().skip(column - 1).take(1).collect();
+    ParseError::new(err.line().max(1), column, "valid JSON", &found)
+}
+
+/// Sum of all numbers in the JSON `document`, ignoring objects with a value
+/// "red".
+pub fn sum_numbers_ignorered(document: &str) -> Result<i64, ParseError> {
+    let parsed: serde_json::Value = serde_json::from_str(document).map_err(|err| json_error(document, err))?;
+    Ok(sum_numbers_json(&parsed))
+}
+
+/// Sum of all numbers in `document`, failing on a number or sum that does
+/// not fit into 64 bits.
+pub fn sum_numbers(document: &str) -> Result<i64, ParseError> {
+    // simple sliding window approach, a lot faster
+    // than actually parsing the json
+    let mut result = 0i64;
+    for line in lines(document) {
+        // byte offset of the current number
+        let mut start = None;
+        for (pos, c) in line.text.char_indices().chain([(line.text.len(), '\n')]) {
+            match (start, c) {
+                (_, '0'..='9') => start = start.or(Some(pos)),
+                // a minus sign only starts a number
+                (None, '-') => start = Some(pos),
+                (Some(i), _) => {
+                    let token = &line.text[i..pos];
+                    if token != "-" {
+                        let value = line.parse::<i64>(token, "a number that fits into 64 bits")?;
+                        result = result.checked_add(value).ok_or_else(|| line.error(token, "a number that keeps the sum within 64 bits"))?;
+                    }
+                    start = (c == '-').then_some(pos);
+                },
+                (None, _) => (),
+            }
+        }
+    }
+    Ok(result)
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    const DAY: u8 = 12;
+    const TITLE: &'static str = "JSAbacusFramework.io";
+    type Input = String;
+
+    fn parse(input: &str) -> Result<String, ParseError> {
+        // part 1 only scans for numbers, but part 2 needs a valid document
+        serde_json::from_str::<Value>(input).map_err(|err| json_error(input, err))?;
+        sum_numbers(input)?;
+        Ok(input.to_string())
+    }
+
+    fn part1(document: &String) -> Option<Answer> {
+        sum_numbers(document).ok().map(Answer::from)
+    }
+
+    fn part2(document: &String) -> Option<Answer> {
+        sum_numbers_ignorered(document).ok().map(Answer::from)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sum_arr() {
+        assert_eq!(Ok(6), sum_numbers("[1,2,3]"));
+    }
+
+    #[test]
+    fn sum_collection() {
+        assert_eq!(Ok(6), sum_numbers("{\"a\":2,\"b\":4}"));
+    }
+
+    #[test]
+    fn sum_nested() {
+        assert_eq!(Ok(3), sum_numbers("[[[3]]]"));
+        assert_eq!(Ok(3), sum_numbers("{\"a\":{\"b\":4},\"c\":-1}"));
+    }
+
+    #[test]
+    fn sum_empty() {
+        assert_eq!(Ok(0), sum_numbers("[]"));
+        assert_eq!(Ok(0), sum_numbers("{}"));
+    }
+
+    #[test]
+    fn sum_negative() {
+        assert_eq!(Ok(0), sum_numbers("{\"a\":[-1,1]}"));
+        assert_eq!(Ok(0), sum_numbers("-1,{\"a\":1}]"));
+    }
+
+    #[test]
+    fn sum_after_multibyte() {
+        // 'é' takes two bytes
+        assert_eq!(Ok(3), sum_numbers("[\"é\",1,2]"));
+    }
+
+    #[test]
+    fn sum_minus_in_strings() {
+        assert_eq!(Ok(1), sum_numbers("[\"-\",\"a--\",1]"));
+    }
+
+    #[test]
+    fn test_parse_error() {
+        assert_eq!(Err(ParseError::new(1, 2, "a number that fits into 64 bits", "99999999999999999999")), Day12::parse("[99999999999999999999]"));
+        assert_eq!(Err(ParseError::new(2, 3, "a number that keeps the sum within 64 bits", "9223372036854775807")),
+                   Day12::parse("[1,\n  9223372036854775807]"));
+        assert_eq!(Err(ParseError::new(1, 3, "valid JSON", "}")), Day12::parse("[1}"));
+        assert_eq!(Err(ParseError::new(1, 3, "valid JSON", "}")), sum_numbers_ignorered("[1}"));
+    }
+
+    #[test]
+    fn sum_ignoreed_ignoreall() {
+        assert_eq!(Ok(0), sum_numbers_ignorered("{\"d\":\"red\",\"e\":[1,2,3,4],\"f\":5}"));
+    }
+
+    #[test]
+    fn sum_ignoreed_ignoreinner() {
+        assert_eq!(Ok(4), sum_numbers_ignorered("[1,{\"c\":\"red\",\"b\":2},3]"));
+    }
+
+    use proptest::prelude::*;
+
+    // documents of nested arrays and objects with numbers and short strings,
+    // the strings never contain digits or '-' so they cannot look like numbers
+    fn document(strings: BoxedStrategy<String>) -> impl Strategy<Value = Value> {
+        let leaf = prop_oneof![
+            any::<i32>().prop_map(Value::from),
+            strings.clone().prop_map(Value::from),
+        ];
+        leaf.prop_recursive(5, 64, 6, move |inner| prop_oneof![
+            prop::collection::vec(inner.clone(), 0..6).prop_map(Value::from),
+            prop::collection::vec((strings.clone(), inner), 0..6).prop_map(|pairs| Value::Object(pairs.into_iter().collect())),
+        ])
+    }
+
+    // the reference for part 2: drop the red objects, then sum up the rest
+    fn without_red(node: &Value) -> Option<Value> {
+        match node {
+            Value::Object(object) if object.values().any(|v| v.as_str() == Some("red")) => None,
+            Value::Object(object) => Some(Value::Object(object.iter().filter_map(|(k, v)| Some((k.clone(), without_red(v)?))).collect())),
+            Value::Array(array) => Some(Value::Array(array.iter().map(|v| without_red(v).unwrap_or(Value::Null)).collect())),
+            _ => Some(node.clone()),
+        }
+    }
+
+    proptest! {
+        #![proptest_config(ProptestConfig::with_cases(2000))]
+
+        #[test]
+        fn sliding_window_matches_walker(doc in document("[a-zé✓ ,:{}\\[\\]\"]{0,6}".prop_filter("no red", |s| s != "red").boxed())) {
+            let compact = serde_json::to_string(&doc).unwrap();
+            let pretty = serde_json::to_string_pretty(&doc).unwrap();
+            prop_assert_eq!(sum_numbers_ignorered(&compact), sum_numbers(&compact));
+            prop_assert_eq!(sum_numbers(&compact), sum_numbers(&pretty));
+        }
+
+        #[test]
+        fn red_objects_are_ignored(doc in document(prop_oneof![Just("red".to_string()), "[a-z]{0,3}"].boxed())) {
+            let expected = without_red(&doc).map_or(Ok(0), |pruned| sum_numbers(&pruned.to_string()));
+            prop_assert_eq!(expected, sum_numbers_ignorered(&doc.to_string()));
+        }
+    }
+}
@@ -0,0 +1,152 @@
+#![allow(unused)]
+#![allow(dead_code)]
+
+use aoc_graph::{held_karp, Goal, Graph, Shape, HELD_KARP_LIMIT};
+use aoc_parse::Template;
+use aoc_runner::{lines, trace, Answer, ParseError, Solution};
+
+type Happiness = isize;
+
+/// The guests, connected by the change in happiness of both when seated
+/// next to each other.
+pub struct HappinessGraph {
+    guests: Graph<Happiness>,
+}
+
+impl HappinessGraph {
+    /// The best seating arrangement around the table, optionally with
+    /// yourself seated as a neutral guest called "me".
+    pub fn best_seating(&self, seat_self: bool) -> Option<(Vec<String>, Happiness)> {
+        let mut table = self.guests.clone();
+        if seat_self {
+            table.intern("me");
+        }
+        // guests who did not mention each other do not mind sitting together
+        for guest1 in 0..table.len() {
+            for guest2 in (0..table.len()).filter(|guest2| *guest2 != guest1) {
+                table.add_weight(guest1, guest2, 0);
+            }
+        }
+        let route = held_karp(&table, Shape::Cycle, Goal::Max)?;
+        let names = table.names(&route).into_iter().map(String::from).collect();
+        Some((names, route.cost))
+    }
+
+    /// Total change in happiness of the best seating arrangement, optionally
+    /// with yourself seated as a neutral guest.
+    pub fn best_total(&self, seat_self: bool) -> Happiness {
+        let Some((seating, happiness)) = self.best_seating(seat_self) else {
+            return 0;
+        };
+        // nobody to sit next to
+        if seating.len() < 2 {
+            return happiness;
+        }
+        trace("seating", || format!("{} and back to {}", seating.join(", "), seating[0]));
+        happiness
+    }
+}
+
+/// Most guests in the input, leaving a seat for yourself.
+pub const MAX_GUESTS: usize = HELD_KARP_LIMIT - 1;
+
+// parsing from lines like
+// "Alice would gain 54 happiness units by sitting next to Bob."
+impl HappinessGraph {
+    pub fn parse(input: &str) -> Result<Self, ParseError> {
+        let template = Template::new("{name:a guest} would {name:'gain' or 'lose'} {int:a number} happiness units by sitting next to {name:a guest}.");
+        let mut guests = Graph::new();
+        for line in lines(input) {
+            let record = template.parse(line)?;
+            let sign = match record.text(1) {
+                "gain" => 1,
+                "lose" => -1,
+                _ => return Err(record.error(1, "'gain' or 'lose'")),
+            };
+            let happiness: Happiness = sign * record.get::<Happiness>(2)?;
+            let guest1 = guests.intern(record.text(0));
+            let guest2 = guests.intern(record.text(3));
+            if guests.len() > MAX_GUESTS {
+                let field = if guest1 == MAX_GUESTS { 0 } else { 3 };
+                return Err(record.error(field, &format!("one of the first {} guests", MAX_GUESTS)));
+            }
+            // seating is symmetric, so both directions get the sum of both changes
+            guests.add_weight(guest1, guest2, happiness);
+            guests.add_weight(guest2, guest1, happiness);
+        }
+        Ok(HappinessGraph { guests })
+    }
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    const DAY: u8 = 13;
+    const TITLE: &'static str = "Knights of the Dinner Table";
+    type Input = HappinessGraph;
+
+    fn parse(input: &str) -> Result<HappinessGraph, ParseError> {
+        HappinessGraph::parse(input)
+    }
+
+    fn part1(graph: &HappinessGraph) -> Option<Answer> {
+        Some(graph.best_total(false).into())
+    }
+
+    fn part2(graph: &HappinessGraph) -> Option<Answer> {
+        Some(graph.best_total(true).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    static TESTINPUT: &str = "Alice would gain 54 happiness units by sitting next to Bob.
+Alice would lose 79 happiness units by sitting next to Carol.
+Alice would lose 2 happiness units by sitting next to David.
+Bob would gain 83 happiness units by sitting next to Alice.
+Bob would lose 7 happiness units by sitting next to Carol.
+Bob would lose 63 happiness units by sitting next to David.
+Carol would lose 62 happiness units by sitting next to Alice.
+Carol would gain 60 happiness units by sitting next to Bob.
+Carol would gain 55 happiness units by sitting next to David.
+David would gain 46 happiness units by sitting next to Alice.
+David would lose 7 happiness units by sitting next to Bob.
+David would gain 41 happiness units by sitting next to Carol.";
+
+    #[test]
+    fn test_part1() {
+        let graph = Day13::parse(TESTINPUT).unwrap();
+        assert_eq!(Some(330.into()), Day13::part1(&graph));
+    }
+
+    #[test]
+    fn test_best_seating() {
+        let graph = Day13::parse(TESTINPUT).unwrap();
+        let (guests, happiness) = graph.best_seating(true).unwrap();
+        assert_eq!(5, guests.len());
+        assert!(guests.contains(&String::from("me")));
+        assert_eq!(happiness, graph.best_total(true));
+    }
+
+    #[test]
+    fn test_explain_empty() {
+        let graph = Day13::parse("").unwrap();
+        for part in [Day13::part1, Day13::part2] {
+            let (answer, events) = aoc_runner::collect(true, || part(&graph));
+            assert_eq!(Some(0.into()), answer);
+            assert!(events.is_empty());
+        }
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let error = Day13::parse("Alice would win 54 happiness units by sitting next to Bob.").map(|_| ()).unwrap_err();
+        assert_eq!(ParseError::new(1, 13, "'gain' or 'lose'", "win"), error);
+        let error = Day13::parse("Alice would gain 54 happiness units by sitting next to Bob").map(|_| ()).unwrap_err();
+        assert_eq!(ParseError::new(1, 59, "'.'", ""), error);
+        let chain: Vec<String> = (0..MAX_GUESTS).map(|n| format!("G{} would gain 1 happiness units by sitting next to G{}.", n, n + 1)).collect();
+        let error = Day13::parse(&chain.join("\n")).map(|_| ()).unwrap_err();
+        assert_eq!(ParseError::new(15, 53, &format!("one of the first {} guests", MAX_GUESTS), "G15"), error);
+    }
+}
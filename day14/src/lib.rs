@@ -0,0 +1,81 @@
+#![allow(unused)]
+#![allow(dead_code)]
+
+use std::{cmp::{min, max}, str::FromStr};
+
+use aoc_parse::numbers;
+use aoc_runner::{lines, Answer, Line, ParseError, Solution};
+
+pub struct Reindeer {
+    speed: usize,
+    flightduration: usize,
+    restduration: usize
+}
+
+impl Reindeer {
+    /// Parse the speed, flight and rest durations of a reindeer description.
+    pub fn parse(line: Line) -> Result<Reindeer, ParseError> {
+        let [speed, flightduration, restduration] = numbers(line, ["a speed", "a flight duration", "a rest duration"])?;
+        Ok(Reindeer{speed, flightduration, restduration})
+    }
+
+    fn period(&self) -> usize {
+        self.flightduration + self.restduration
+    }
+
+    /// Distance flown after `time` seconds.
+    pub fn get_distance(&self, time: usize) -> usize {
+        self.speed * ((time / self.period()) * self.flightduration
+                       + min(time % self.period(), self.flightduration))
+    }
+}
+
+/// Points of the winning reindeer after `duration` seconds, when every second
+/// the reindeer in the lead get a point.
+pub fn most_points(reindeers: &[Reindeer], duration: usize) -> usize {
+    let mut points = vec![0usize; reindeers.len()];
+    let mut argmax = vec![0];
+    for time in 1..duration {
+        argmax.clear();
+        // brute force, no optimization
+        let mut best = 0;
+        for (i, dist) in reindeers.iter().map(|rd| rd.get_distance(time)).enumerate() {
+            if dist > best {
+                best = dist;
+                argmax.clear();
+                argmax.push(i);
+            } else if dist == best {
+                argmax.push(i);
+            }
+        }
+        for i in argmax.iter() {
+            points[*i] += 1;
+        }
+    }
+    points.iter().max().copied().unwrap_or(0)
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    const DAY: u8 = 14;
+    const TITLE: &'static str = "Reindeer Olympics";
+    type Input = Vec<Reindeer>;
+
+    fn parse(input: &str) -> Result<Vec<Reindeer>, ParseError> {
+        lines(input).map(Reindeer::parse).collect()
+    }
+
+    fn part1(reindeers: &Vec<Reindeer>) -> Option<Answer> {
+        let mut best = 0;
+        let duration = 2503;
+        for reindeer in reindeers {
+            best = max(best, reindeer.get_distance(duration));
+        }
+        Some(best.into())
+    }
+
+    fn part2(reindeers: &Vec<Reindeer>) -> Option<Answer> {
+        Some(most_points(reindeers, 2503).into())
+    }
+}
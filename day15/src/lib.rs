@@ -0,0 +1,189 @@
+#![allow(unused)]
+#![allow(dead_code)]
+
+use std::ops::{Add, Sub, Mul};
+use std::sync::LazyLock;
+
+use aoc_parse::Template;
+use aoc_runner::{lines, Answer, Line, ParseError, Solution};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct CookieProperties {
+    capacity: i64,
+    durability: i64,
+    flavor: i64,
+    texture: i64,
+    calories: i64
+}
+
+impl CookieProperties {
+    fn value(&self) -> i64 {
+        if self.capacity <= 0 || self.durability <= 0 || self.flavor <= 0 || self.texture <= 0 {
+            return 0;
+        }
+        self.capacity * self.durability * self.flavor * self.texture
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Ingredient {
+    properites: CookieProperties
+}
+
+static TEMPLATE: LazyLock<Template> = LazyLock::new(|| {
+    Template::new("{name:an ingredient}: capacity {int:a capacity}, durability {int:a durability}, \
+                   flavor {int:a flavor}, texture {int:a texture}, calories {int:calories}")
+});
+
+impl CookieProperties {
+    fn parse(line: Line) -> Result<CookieProperties, ParseError> {
+        let record = TEMPLATE.parse(line)?;
+        Ok(CookieProperties { capacity: record.get(1)?, durability: record.get(2)?, flavor: record.get(3)?,
+                              texture: record.get(4)?, calories: record.get(5)? })
+    }
+}
+
+impl Mul<i64> for CookieProperties {
+    type Output = CookieProperties;
+
+    fn mul(self, rhs: i64) -> Self::Output {
+        CookieProperties { capacity: self.capacity * rhs, durability: self.durability * rhs,
+                           flavor: self.flavor * rhs, texture: self.texture * rhs,
+                           calories: self.calories * rhs }
+    }
+}
+
+impl Add for CookieProperties {
+    type Output = CookieProperties;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        CookieProperties { capacity: self.capacity + rhs.capacity, durability: self.durability + rhs.durability,
+                           flavor: self.flavor + rhs.flavor, texture: self.texture + rhs.texture,
+                           calories: self.calories + rhs.calories }
+    }
+}
+
+use std::iter::Sum;
+impl Sum for CookieProperties {
+    fn sum<I: Iterator<Item=CookieProperties>>(iter: I) -> Self {
+        iter.fold(CookieProperties { capacity: 0, durability: 0, flavor: 0, texture: 0, calories: 0 }, |a, b| a + b)
+    }
+}
+
+impl Ingredient {
+    /// Parse the properties from a line like
+    /// "Butterscotch: capacity -1, durability -2, flavor 6, texture 3, calories 8".
+    pub fn parse(line: Line) -> Result<Ingredient, ParseError> {
+        Ok(Ingredient { properites: CookieProperties::parse(line)? })
+    }
+}
+
+use std::cmp::max;
+/// Score of the best cookie using exactly `max_teaspoons` of the
+/// `ingredients`, optionally only counting cookies with `calorie_target` calories.
+pub fn bestcookie(ingredients: &[Ingredient], max_teaspoons: usize, calorie_target: Option<i64>) -> i64 {
+    // brute-force all possible combinations
+    let num_ingredients = ingredients.len();
+    if num_ingredients < 2 {
+        // there is nothing to combine
+        let cookie = ingredients.iter().map(|ingr| ingr.properites * max_teaspoons as i64).sum::<CookieProperties>();
+        return match calorie_target {
+            Some(calories) if cookie.calories != calories => 0,
+            _ => cookie.value(),
+        };
+    }
+    let mut separators = vec![0usize; ingredients.len() - 1];
+    let mut teaspoons = vec![0usize; num_ingredients];
+    teaspoons[0] = max_teaspoons;
+    // **|****|*|***
+    let mut best = 0;
+    loop {
+        assert!(teaspoons.iter().sum::<usize>() == max_teaspoons); //invariant
+        let cookie = teaspoons.iter().zip(ingredients).map(|(n, ingr)| ingr.properites * *n as i64).sum::<CookieProperties>();
+        best = match calorie_target {
+            None => max(best, cookie.value()),
+            Some(calories) => if cookie.calories == calories {max(best, cookie.value())} else {best}
+        };
+
+        // very inelegant way of creating all possible combinations
+        // iterate separators
+        for (i, separator) in separators.iter_mut().enumerate() {
+            if *separator != max_teaspoons {
+                *separator += 1;
+                break;
+            } else if i < num_ingredients - 2 {
+                // carry
+                *separator = 0;
+            } else {
+                // done
+                return best;
+            }
+        }
+        // restore order
+        for i in (0..num_ingredients - 2).rev() {
+            separators[i] = max(separators[i], separators[i + 1]);
+        }
+        // renew teaspoons
+        for (i, teaspoon) in teaspoons.iter_mut().enumerate() {
+            if i == 0 {
+                *teaspoon = max_teaspoons - separators[0];
+            } else if i == num_ingredients - 1 {
+                *teaspoon = separators[num_ingredients - 2]
+            } else {
+                *teaspoon = separators[i - 1] - separators[i];
+            }
+        }
+    }
+}
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    const DAY: u8 = 15;
+    const TITLE: &'static str = "Science for Hungry People";
+    type Input = Vec<Ingredient>;
+
+    fn parse(input: &str) -> Result<Vec<Ingredient>, ParseError> {
+        if input.trim().is_empty() {
+            return Err(ParseError::new(1, 1, "an ingredient", ""));
+        }
+        lines(input).map(Ingredient::parse).collect()
+    }
+
+    fn part1(ingredients: &Vec<Ingredient>) -> Option<Answer> {
+        Some(bestcookie(ingredients, 100, None).into())
+    }
+
+    fn part2(ingredients: &Vec<Ingredient>) -> Option<Answer> {
+        Some(bestcookie(ingredients, 100, Some(500)).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    static TESTINPUT: &str = "Butterscotch: capacity -1, durability -2, flavor 6, texture 3, calories 8
+Cinnamon: capacity 2, durability 3, flavor -2, texture -1, calories 3";
+
+    #[test]
+    fn test_parts() {
+        let ingredients = Day15::parse(TESTINPUT).unwrap();
+        assert_eq!(Some(62842880.into()), Day15::part1(&ingredients));
+        assert_eq!(Some(57600000.into()), Day15::part2(&ingredients));
+    }
+
+    #[test]
+    fn test_single_ingredient() {
+        let ingredients = Day15::parse("Sugar: capacity 0, durability 0, flavor 0, texture 0, calories 0").unwrap();
+        assert_eq!(Some(0.into()), Day15::part1(&ingredients));
+        let ingredients = Day15::parse("Sugar: capacity 1, durability 2, flavor 3, texture 4, calories 5").unwrap();
+        assert_eq!(Some(2400000000i64.into()), Day15::part1(&ingredients));
+        assert_eq!(Some(2400000000i64.into()), Day15::part2(&ingredients));
+    }
+
+    #[test]
+    fn test_parse_error() {
+        assert_eq!(Err(ParseError::new(1, 1, "an ingredient", "")), Day15::parse(""));
+    }
+}
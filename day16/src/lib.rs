@@ -0,0 +1,88 @@
+#![allow(unused)]
+#![allow(dead_code)]
+
+use std::collections::HashMap;
+
+use aoc_parse::key_values;
+use aoc_runner::{lines, Answer, Line, ParseError, Solution};
+
+static FACTS: &str = "children: 3, cats: 7, samoyeds: 2, pomeranians: 3, akitas: 0, vizslas: 0, goldfish: 5, trees: 3, cars: 2, perfumes: 1";
+
+/// Parse facts like "Sue 1: cars: 9, akitas: 3", the leading "Sue N:" is optional.
+pub fn parse_facts(line: Line) -> Result<HashMap<String, i64>, ParseError> {
+    let mut facts = line.text;
+    if facts.starts_with("Sue") {
+        (_, facts) = line.split_once(facts, ": ")?;
+    }
+    let facts = key_values(line, facts, ", ", ": ", "a number")?;
+    Ok(facts.into_iter().map(|(key, value)| (key.to_string(), value)).collect())
+}
+
+/// What the MFCSAM found out about the gift.
+pub fn known_facts() -> HashMap<String, i64> {
+    parse_facts(Line { number: 1, text: FACTS }).unwrap()
+}
+
+/// Number of the first of the `aunts` matching the known facts. With
+/// `ranges`, cats and trees readings are lower bounds and pomeranians and
+/// goldfish readings are upper bounds.
+pub fn find_aunt(aunts: &[HashMap<String, i64>], ranges: bool) -> Option<usize> {
+    let facts = known_facts();
+
+    let comparator = |key: &str, val1: Option<&i64>, val2: i64| -> bool {
+        if let Some(v1) = val1 {
+            if ranges && (key == "cats" || key == "trees") {
+                return *v1 < val2;
+            } else if ranges && (key == "pomeranians" || key == "goldfish") {
+                return *v1 > val2;
+            }
+            return *v1 == val2;
+        }
+        true
+    };
+
+    'auntloop: for (i, mfcsam) in aunts.iter().enumerate() {
+        for (key, val) in mfcsam.iter() {
+            if !comparator(key, facts.get(key), *val) {
+                continue 'auntloop;
+            }
+        }
+        return Some(i + 1);
+    }
+    None
+}
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    const DAY: u8 = 16;
+    const TITLE: &'static str = "Aunt Sue";
+    // the facts remembered about each aunt, in order of their number
+    type Input = Vec<HashMap<String, i64>>;
+
+    fn parse(input: &str) -> Result<Vec<HashMap<String, i64>>, ParseError> {
+        lines(input).map(parse_facts).collect()
+    }
+
+    fn part1(aunts: &Vec<HashMap<String, i64>>) -> Option<Answer> {
+        find_aunt(aunts, false).map(Answer::from)
+    }
+
+    fn part2(aunts: &Vec<HashMap<String, i64>>) -> Option<Answer> {
+        find_aunt(aunts, true).map(Answer::from)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let facts = known_facts();
+        assert_eq!(facts.get("children"), Some(&3));
+        assert_eq!(facts.get("cats"), Some(&7));
+        assert_eq!(facts.get("cars"), Some(&2));
+        assert_eq!(facts.get("perfumes"), Some(&1));
+    }
+}
@@ -0,0 +1,131 @@
+#![allow(unused)]
+#![allow(dead_code)]
+
+use aoc_runner::{lines, Answer, ParseError, Solution};
+
+/// Parse one bucket capacity per line.
+pub fn parse_buckets(input: &str) -> Result<Vec<i64>, ParseError> {
+    lines(input).map(|line| line.parse::<i64>(line.text, "a bucket size"))
+                .collect()
+}
+
+/// Number of combinations of `buckets` holding exactly `target_capacity`.
+pub fn combinations(buckets: &[i64], target_capacity: i64) -> usize {
+    let num_buckets = buckets.len();
+    if target_capacity == 0 {
+        return 1;
+    } else if target_capacity < 0 || num_buckets == 0 {
+        return 0;
+    }
+    combinations(&buckets[1..num_buckets], target_capacity - buckets[0])
+    + combinations(&buckets[1..num_buckets], target_capacity)
+}
+
+/// Number of combinations of exactly `num_containers` of the `buckets`
+/// holding exactly `target_capacity`.
+pub fn bounded_combinations(buckets: &[i64], target_capacity: i64, num_containers: usize) -> usize {
+    let num_buckets = buckets.len();
+    if target_capacity == 0  && num_containers == 0{
+        return 1;
+    } else if target_capacity < 0 || num_buckets == 0 || num_containers == 0 {
+        return 0;
+    }
+    bounded_combinations(&buckets[1..num_buckets], target_capacity - buckets[0], num_containers - 1)
+    + bounded_combinations(&buckets[1..num_buckets], target_capacity, num_containers)
+}
+
+/// Number of combinations holding exactly `target_capacity` that use as few
+/// of the `buckets` as possible.
+pub fn fewest_containers_combinations(buckets: &[i64], target_capacity: i64) -> usize {
+    let mut buckets = buckets.to_vec();
+    buckets.sort();
+    // minor optim: the largest buckets give a lower bound
+    // on the number of containers
+    let smallest = (0..=buckets.len()).find(|n| buckets.iter().rev().take(*n).sum::<i64>() >= target_capacity)
+                                      .unwrap_or(buckets.len() + 1);
+    // the largest buckets may overshoot, so more containers can be needed
+    (smallest..=buckets.len()).map(|containers| bounded_combinations(&buckets, target_capacity, containers))
+                              .find(|combinations| *combinations > 0)
+                              .unwrap_or(0)
+}
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    const DAY: u8 = 17;
+    const TITLE: &'static str = "No Such Thing as Too Much";
+    type Input = Vec<i64>;
+
+    fn parse(input: &str) -> Result<Vec<i64>, ParseError> {
+        parse_buckets(input)
+    }
+
+    fn part1(buckets: &Vec<i64>) -> Option<Answer> {
+        const TARGETCAPACITY: usize = 150;
+        Some(combinations(buckets, TARGETCAPACITY as i64).into())
+    }
+
+    fn part2(buckets: &Vec<i64>) -> Option<Answer> {
+        const TARGETCAPACITY: usize = 150;
+        Some(fewest_containers_combinations(buckets, TARGETCAPACITY as i64).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    static TESTINPUT: &str = "20\n15\n10\n5\n5";
+
+    #[test]
+    fn test_part1() {
+        let buckets = Day17::parse(TESTINPUT).unwrap();
+        assert_eq!(4, combinations(&buckets, 25));
+    }
+
+    #[test]
+    fn test_part2() {
+        let buckets = Day17::parse(TESTINPUT).unwrap();
+        assert_eq!(3, fewest_containers_combinations(&buckets, 25));
+    }
+
+    use proptest::prelude::*;
+
+    // some buckets and a capacity that may be out of reach
+    fn buckets_and_target() -> impl Strategy<Value = (Vec<i64>, i64)> {
+        prop::collection::vec(1i64..=50, 0..=12)
+            .prop_flat_map(|buckets| {
+                let total = buckets.iter().sum::<i64>();
+                (Just(buckets), 0..=total + 5)
+            })
+    }
+
+    // the number of buckets of every combination holding the target
+    fn reference(buckets: &[i64], target_capacity: i64) -> Vec<usize> {
+        (0..1usize << buckets.len())
+            .filter(|set| (0..buckets.len()).filter(|i| set & (1 << i) != 0).map(|i| buckets[i]).sum::<i64>() == target_capacity)
+            .map(|set| set.count_ones() as usize)
+            .collect()
+    }
+
+    proptest! {
+        #![proptest_config(ProptestConfig::with_cases(2000))]
+
+        #[test]
+        fn combinations_match_subsets((buckets, target) in buckets_and_target()) {
+            let sizes = reference(&buckets, target);
+            prop_assert_eq!(sizes.len(), combinations(&buckets, target));
+            for containers in 0..=buckets.len() {
+                let expected = sizes.iter().filter(|size| **size == containers).count();
+                prop_assert_eq!(expected, bounded_combinations(&buckets, target, containers));
+            }
+            let fewest = sizes.iter().min().map_or(0, |min| sizes.iter().filter(|size| *size == min).count());
+            prop_assert_eq!(fewest, fewest_containers_combinations(&buckets, target));
+        }
+    }
+
+    #[test]
+    fn test_fewest_overshoot() {
+        // the 10 overshoots, the three small buckets are needed
+        assert_eq!(1, fewest_containers_combinations(&[10, 1, 1, 1], 3));
+    }
+}
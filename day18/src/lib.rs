@@ -0,0 +1,78 @@
+#![allow(unused)]
+#![allow(dead_code)]
+
+use aoc_grid::Grid2D;
+use aoc_runner::{Answer, ParseError, Solution};
+
+/// Parse a grid of '#' (on) and '.' (off) lights.
+pub fn parse_lights(input: &str) -> Result<Grid2D<bool>, ParseError> {
+    Grid2D::parse(input, |c| match c {
+        '#' => Some(true),
+        '.' => Some(false),
+        _ => None,
+    }, "'#' or '.'")
+}
+
+/// Number of lights on after `num_steps` steps, optionally with the corners
+/// stuck on.
+pub fn game_of_life(grid: Grid2D<bool>, num_steps: usize, freeze_corners: bool) -> usize {
+    assert!(num_steps > 0);
+    let mut grid = grid;
+    let width = grid.width();
+    let height = grid.height();
+    let is_corner = |(x, y): (usize, usize)| (x == 0 || x + 1 == width) && (y == 0 || y + 1 == height);
+    if freeze_corners {
+        for position in grid.positions().filter(|position| is_corner(*position)).collect::<Vec<_>>() {
+            grid[position] = true;
+        }
+    }
+    for step in 0..num_steps {
+        let mut nextgrid = Grid2D::new(width, height, false);
+        for (x, y) in grid.positions() {
+            let nsum = grid.neighbors8(x, y).filter(|neighbor| grid[*neighbor]).count();
+            // a light stays on with 2 or 3 neighbors on, and turns on with 3
+            nextgrid[(x, y)] = nsum == 3 || grid[(x, y)] && nsum == 2
+                                || freeze_corners && is_corner((x, y));
+        }
+        grid = nextgrid;
+    }
+    grid.iter().filter(|on| **on).count()
+}
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    const DAY: u8 = 18;
+    const TITLE: &'static str = "Like a GIF For Your Yard";
+    type Input = Grid2D<bool>;
+
+    fn parse(input: &str) -> Result<Grid2D<bool>, ParseError> {
+        parse_lights(input)
+    }
+
+    fn part1(grid: &Grid2D<bool>) -> Option<Answer> {
+        Some(game_of_life(grid.clone(), 100, false).into())
+    }
+
+    fn part2(grid: &Grid2D<bool>) -> Option<Answer> {
+        Some(game_of_life(grid.clone(), 100, true).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    static TESTINPUT: &str = ".#.#.#\n...##.\n#....#\n..#...\n#.#..#\n####..";
+
+    #[test]
+    fn test_game_of_life() {
+        let grid = Day18::parse(TESTINPUT).unwrap();
+        assert_eq!(4, game_of_life(grid, 4, false));
+    }
+
+    #[test]
+    fn test_game_of_life_frozen_corners() {
+        let grid = Day18::parse(TESTINPUT).unwrap();
+        assert_eq!(17, game_of_life(grid, 5, true));
+    }
+}
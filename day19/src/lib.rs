@@ -0,0 +1,175 @@
+#![allow(unused)]
+#![allow(dead_code)]
+
+use aoc_runner::{lines, Answer, Line, ParseError, Solution};
+use aoc_search::{search, SearchState, Strategy};
+
+#[derive(Debug, Hash, PartialEq, Eq)]
+pub struct Rule {
+    input: String,
+    output: String,
+}
+
+impl Rule {
+    /// Parse a replacement like "H => HO".
+    pub fn parse(line: Line) -> Result<Rule, ParseError> {
+        let (input, output) = line.split_once(line.text, " => ")?;
+        Ok(Rule {input: input.to_string(), output: output.to_string()})
+    }
+}
+
+//implement ordering for Rule solely based on the input
+impl Ord for Rule {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.input.cmp(&other.input)
+    }
+}
+
+impl PartialOrd for Rule {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+#[derive(Debug)]
+pub struct Grammar {
+    rules: Vec<Rule>
+}
+
+impl From<Vec<Rule>> for Grammar {
+    fn from(mut rules: Vec<Rule>) -> Self {
+        rules.sort();
+        Grammar {rules}
+    }
+}
+
+// a molecule on the way back from the medicine
+#[derive(Debug, Clone)]
+struct Reduction<'a> {
+    grammar: &'a Grammar,
+    goal: &'a str,
+    limit: usize,
+    molecule: String,
+}
+
+impl SearchState for Reduction<'_> {
+    type Key = String;
+    type Cost = usize;
+
+    // undo each replacement wherever its output occurs
+    fn successors(&self) -> Vec<(Self, usize)> {
+        let mut next = Vec::new();
+        for (charpos, c) in self.molecule.char_indices() {
+            let (prefix, suffix) = self.molecule.split_at(charpos);
+            for rule in self.grammar.rules.iter().filter(|r| suffix.starts_with(&r.output)) {
+                let mut result = prefix.to_owned();
+                result.push_str(&rule.input);
+                result.push_str(&suffix[rule.output.len()..]);
+                if result.len() <= self.limit {
+                    next.push((Reduction{molecule: result, ..*self}, 0));
+                }
+            }
+        }
+        next
+    }
+
+    fn is_goal(&self) -> bool {
+        self.molecule == self.goal
+    }
+
+    fn key(&self) -> String {
+        self.molecule.clone()
+    }
+
+    fn heuristic(&self) -> usize {
+        self.molecule.len()
+    }
+}
+
+impl Grammar {
+    /// All distinct molecules one replacement away from `inputstr`.
+    pub fn replacements(&self, inputstr: &str) -> std::collections::HashSet<String> {
+        use std::collections::HashSet;
+        let mut seen: HashSet<String> = HashSet::new();
+        for (charpos, c) in inputstr.char_indices() {
+            let (prefix, suffix) = inputstr.split_at(charpos);
+            for rule in self.rules.iter().filter(|r| suffix.starts_with(&r.input)) {
+                let mut result = prefix.to_owned();
+                result.push_str(&rule.output);
+                result.push_str(&suffix[rule.input.len()..]);
+                seen.insert(result);
+            }
+        }
+        seen
+    }
+
+    /// Fewest replacements to make `medicine` starting from `goal`, searching
+    /// backwards from the medicine. Not guaranteed to be minimal for every grammar.
+    pub fn fewest_steps(&self, goal: &str, medicine: &str) -> Option<usize> {
+        let start = Reduction{grammar: self, goal, limit: medicine.len(), molecule: medicine.to_string()};
+        // without step costs A* always continues with the shortest molecule,
+        // which finds a way quickly but not necessarily the shortest one
+        search(start, Strategy::AStar).found.map(|found| found.steps())
+    }
+}
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    const DAY: u8 = 19;
+    const TITLE: &'static str = "Medicine for Rudolph";
+    // replacement rules and the medicine molecule
+    type Input = (Grammar, String);
+
+    // the rules, followed by the molecule after a blank line
+    fn parse(input: &str) -> Result<(Grammar, String), ParseError> {
+        let lines: Vec<Line> = lines(input).collect();
+        let Some((molecule, rules)) = lines.split_last() else {
+            return Err(ParseError::new(1, 1, "a molecule", ""));
+        };
+        if molecule.text.is_empty() {
+            return Err(molecule.error_at_end("a molecule"));
+        }
+        if let Some(arrow) = molecule.text.find(" => ") {
+            return Err(molecule.error(&molecule.text[arrow..arrow + 4], "a molecule after the rules"));
+        }
+        let rules = rules.iter()
+                         .filter(|line| !line.text.is_empty())
+                         .map(|line| Rule::parse(*line))
+                         .collect::<Result<Vec<_>, _>>()?;
+        Ok((Grammar::from(rules), molecule.text.to_string()))
+    }
+
+    fn part1((grammar, molecule): &(Grammar, String)) -> Option<Answer> {
+        Some(grammar.replacements(molecule).len().into())
+    }
+
+    fn part2((grammar, medicine): &(Grammar, String)) -> Option<Answer> {
+        grammar.fewest_steps("e", medicine).map(Answer::from)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    static TESTINPUT: &str = "e => H\ne => O\nH => HO\nH => OH\nO => HH\n\nHOH";
+    static TESTINPUT2: &str = "e => H\ne => O\nH => HO\nH => OH\nO => HH\n\nHOHOHO";
+
+    #[test]
+    fn test_part1() {
+        let input = Day19::parse(TESTINPUT).unwrap();
+        assert_eq!(Some(4.into()), Day19::part1(&input));
+    }
+
+    #[test]
+    fn test_part1_longer() {
+        let input = Day19::parse(TESTINPUT2).unwrap();
+        assert_eq!(Some(7.into()), Day19::part1(&input));
+    }
+
+    #[test]
+    fn test_part2() {
+        let input = Day19::parse(TESTINPUT).unwrap();
+        assert_eq!(Some(3.into()), Day19::part2(&input));
+    }
+}
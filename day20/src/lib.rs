@@ -0,0 +1,120 @@
+use aoc_math::{bounded_divisor_sums, divisor_sums};
+use aoc_runner::{lines, Answer, Line, ParseError, Solution};
+
+// elf n brings presents to every n-th house, so house h gets presents from
+// the elves numbered by the divisors of h
+
+/// Presents of every house up to `houses`, when each elf brings 10 times its
+/// number. `presents[0]` is unused.
+fn presents(houses: usize) -> Vec<usize> {
+    divisor_sums(houses).into_iter().map(|sum| 10 * sum).collect()
+}
+
+/// Like [`presents`], but elves bring 11 times their number to only
+/// `max_houses` houses.
+fn presents_lazy_elves(houses: usize, max_houses: usize) -> Vec<usize> {
+    bounded_divisor_sums(houses, max_houses).into_iter().map(|sum| 11 * sum).collect()
+}
+
+fn first_house(presents: &[usize], goal: usize) -> Option<usize> {
+    presents.iter().enumerate().skip(1).find(|(_, presents)| **presents >= goal).map(|(house, _)| house)
+}
+
+/// Lowest house number receiving at least `goal` presents.
+pub fn lowest_house(goal: usize) -> Option<usize> {
+    // elf h alone brings 10 * h presents to house h
+    first_house(&presents(goal.div_ceil(10).max(1)), goal)
+}
+
+/// Lowest house number receiving at least `goal` presents when each elf
+/// delivers 11 presents to only `max_houses` houses.
+pub fn lowest_house_lazy_elves(goal: usize, max_houses: usize) -> Option<usize> {
+    first_house(&presents_lazy_elves(goal.div_ceil(11).max(1), max_houses), goal)
+}
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    const DAY: u8 = 20;
+    const TITLE: &'static str = "Infinite Elves and Infinite Houses";
+    type Input = usize;
+
+    fn parse(input: &str) -> Result<usize, ParseError> {
+        let line = lines(input).next().unwrap_or(Line { number: 1, text: "" });
+        if line.text.trim().is_empty() {
+            return Err(line.error_at_end("a number of presents"));
+        }
+        line.parse(line.text.trim(), "a number of presents")
+    }
+
+    fn part1(goal: &usize) -> Option<Answer> {
+        lowest_house(*goal).map(Answer::from)
+    }
+
+    fn part2(goal: &usize) -> Option<Answer> {
+        lowest_house_lazy_elves(*goal, 50).map(Answer::from)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_presents() {
+        assert_eq!(vec![0, 10, 30, 40, 70, 60, 120, 80, 150, 130], presents(9));
+        assert_eq!(150 + 50 + 30 + 10, presents(15)[15]);
+        assert_eq!(Some(8), lowest_house(150));
+    }
+
+    #[test]
+    fn test_presents_lazy_elves() {
+        let presents = presents_lazy_elves(51, 50);
+        assert_eq!(vec![0, 11, 33, 44, 77, 66, 66 + 33 + 22 + 11], presents[..7]);
+        // elf 1 stops after house 50
+        assert_eq!(11 * (51 + 17 + 3), presents[51]);
+    }
+
+    #[test]
+    fn test_lowest_house_small_goals() {
+        assert_eq!(Some(1), lowest_house(10));
+        assert_eq!(Some(4), lowest_house(70));
+        assert_eq!(Some(1), lowest_house_lazy_elves(11, 50));
+    }
+
+    use proptest::prelude::*;
+
+    fn divisors(num: usize) -> Vec<usize> {
+        (1..=num).filter(|d| num.is_multiple_of(*d)).collect()
+    }
+
+    // presents of every house up to `houses`, delivered elf by elf
+    fn deliveries(houses: usize, per_elf: usize, max_houses: usize) -> Vec<usize> {
+        let mut presents = vec![0; houses + 1];
+        for elf in 1..=houses {
+            for house in (elf..=houses).step_by(elf).take(max_houses) {
+                presents[house] += per_elf * elf;
+            }
+        }
+        presents
+    }
+
+    proptest! {
+        #![proptest_config(ProptestConfig::with_cases(2000))]
+
+        #[test]
+        fn presents_match_divisors(num in 1usize..2_000) {
+            prop_assert_eq!(10 * divisors(num).iter().sum::<usize>(), presents(num)[num]);
+            let lazy = divisors(num).into_iter().filter(|d| num / d <= 50).sum::<usize>();
+            prop_assert_eq!(11 * lazy, presents_lazy_elves(num, 50)[num]);
+        }
+
+        #[test]
+        fn lowest_house_matches_deliveries(goal in 1usize..10_000) {
+            let houses = deliveries(goal / 10 + 1, 10, usize::MAX);
+            prop_assert_eq!(houses.iter().position(|p| *p >= goal), lowest_house(goal));
+            let houses = deliveries(goal / 11 + 1, 11, 50);
+            prop_assert_eq!(houses.iter().position(|p| *p >= goal), lowest_house_lazy_elves(goal, 50));
+        }
+    }
+}
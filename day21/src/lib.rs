@@ -0,0 +1,245 @@
+#![allow(unused)]
+#![allow(dead_code)]
+
+use aoc_parse::Template;
+use aoc_runner::{lines, trace, Answer, Line, ParseError, Solution};
+
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Stats {
+    damage: i64,
+    armor: i64
+}
+
+use std::ops::{Add, Sub, AddAssign, SubAssign};
+
+impl Add for Stats {
+    type Output = Stats;
+
+    fn add(self, other: Stats) -> Stats {
+        Stats { damage: self.damage + other.damage, armor: self.armor + other.armor }
+    }
+}
+
+impl Sub for Stats {
+    type Output = Stats;
+
+    fn sub(self, other: Stats) -> Stats {
+        Stats { damage: self.damage - other.damage, armor: self.armor - other.armor }
+    }
+}
+
+impl AddAssign for Stats {
+    fn add_assign(&mut self, other: Stats) {
+        *self = Stats { damage: self.damage + other.damage, armor: self.armor + other.armor };
+    }
+}
+
+impl SubAssign for Stats {
+    fn sub_assign(&mut self, other: Stats) {
+        *self = Stats { damage: self.damage - other.damage, armor: self.armor - other.armor };
+    }
+}
+
+// macro for creating a Stats struct from two i64s
+macro_rules! stats {
+    ($d:expr, $a:expr) => {
+        Stats { damage: $d, armor: $a }
+    };
+}
+
+#[derive(Debug, Clone, Copy)]
+struct ShopItem {
+    name: &'static str,
+    stats: Stats,
+    cost: i64
+}
+
+// the cost and stats of all items together
+fn equip(items: [&ShopItem; 4]) -> (i64, Stats) {
+    items.iter().fold((0, stats!(0, 0)), |(cost, stats), item| (cost + item.cost, stats + item.stats))
+}
+
+// the items actually bought, without the dummies
+fn loadout(items: [&ShopItem; 4]) -> String {
+    let (cost, stats) = equip(items);
+    let names: Vec<&str> = items.iter().map(|item| item.name).filter(|name| !name.is_empty()).collect();
+    format!("{} for {} gold, {} damage and {} armor", names.join(", "), cost, stats.damage, stats.armor)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Entity {
+    hp: i64,
+    stats: Stats
+}
+
+macro_rules! shopitem {
+    ($n:expr, $c:expr, $d:expr, $a:expr) => {
+        ShopItem { name: $n, cost: $c, stats: stats!($d, $a)}
+    };
+}
+
+struct Shop {
+    weapons: [ShopItem; 5],
+    armor: [ShopItem; 6],
+    rings: [ShopItem; 8]
+}
+
+impl Shop {
+    fn new() -> Shop {
+        Shop {
+            weapons: [
+                shopitem!("Dagger", 8, 4, 0),
+                shopitem!("Shortsword", 10, 5, 0),
+                shopitem!("Warhammer", 25, 6, 0),
+                shopitem!("Longsword", 40, 7, 0),
+                shopitem!("Greataxe", 74, 8, 0),
+            ],
+            armor: [
+                shopitem!("", 0, 0, 0), // dummy
+                shopitem!("Leather", 13, 0, 1),
+                shopitem!("Chainmail", 31, 0, 2),
+                shopitem!("Splintmail", 53, 0, 3),
+                shopitem!("Bandedmail", 75, 0, 4),
+                shopitem!("Platemail", 102, 0, 5),
+            ],
+            rings: [
+                shopitem!("", 0, 0, 0), //dummy
+                shopitem!("", 0, 0, 0), //dummy
+                shopitem!("Damage +1", 25, 1, 0),
+                shopitem!("Damage +2", 50, 2, 0),
+                shopitem!("Damage +3", 100, 3, 0),
+                shopitem!("Defense +1", 20, 0, 1),
+                shopitem!("Defense +2", 40, 0, 2),
+                shopitem!("Defense +3", 80, 0, 3),
+            ]
+        }
+    }
+}
+
+impl Entity {
+    pub fn new(hp: i64, damage: i64, armor: i64) -> Entity {
+        Entity{hp, stats: stats!(damage, armor)}
+    }
+
+    /// Parse the hit points, damage and armor of the boss, one per line.
+    pub fn parse(input: &str) -> Result<Entity, ParseError> {
+        let lines: Vec<Line> = lines(input).collect();
+        let parsenum = |index: usize, pattern, expected| -> Result<i64, ParseError> {
+            let line = lines.get(index).ok_or_else(|| ParseError::new(index + 1, 1, expected, ""))?;
+            Template::new(pattern).parse(*line)?.get(0)
+        };
+        Ok(Entity{hp: parsenum(0, "Hit Points: {int:hit points}", "'Hit Points'")?,
+                  stats: Stats{damage: parsenum(1, "Damage: {int:damage}", "'Damage'")?,
+                               armor: parsenum(2, "Armor: {int:armor}", "'Armor'")?}})
+    }
+}
+
+/// Whether `player` wins the fight against `boss`, attacking first.
+pub fn defeats(player: &Entity, boss: &Entity) -> bool {
+    let player_dmg = (player.stats.damage - boss.stats.armor).max(1);
+    let boss_dmg = (boss.stats.damage - player.stats.armor).max(1);
+    let ttk = boss.hp / player_dmg + (if boss.hp % player_dmg > 0 { 1 } else { 0 });
+    let alivetime = player.hp / boss_dmg + (if player.hp % boss_dmg > 0 { 1 } else { 0 });
+    ttk <= alivetime
+}
+
+/// Least amount of gold to spend on equipment and still win against `boss`,
+/// `None` if no equipment wins.
+pub fn cheapest_win(boss: &Entity) -> Option<i64> {
+    let shop = Shop::new();
+    let player = Entity{hp: 100, stats: Stats{damage: 0, armor: 0}};
+    let mut best = i64::MAX;
+    let mut best_items = None;
+    for weapon in shop.weapons.iter() {
+        for armor in shop.armor.iter() {
+            for (i, ring1) in shop.rings.iter().enumerate() {
+                // the shop has only one of each ring
+                for ring2 in shop.rings.iter().skip(i + 1) {
+                    let (cost, stats) = equip([weapon, armor, ring1, ring2]);
+                    let equipped_player = Entity{hp: player.hp, stats};
+                    if cost < best && defeats(&equipped_player, boss) {
+                        best = cost;
+                        best_items = Some([weapon, armor, ring1, ring2]);
+                    }
+                }
+            }
+        }
+    }
+    let items = best_items?;
+    trace("loadout", || loadout(items));
+    Some(best)
+}
+
+/// Most amount of gold to spend on equipment and still lose against `boss`,
+/// `None` if every equipment wins.
+pub fn most_expensive_loss(boss: &Entity) -> Option<i64> {
+    let shop = Shop::new();
+    let player = Entity{hp: 100, stats: Stats{damage: 0, armor: 0}};
+    let mut best = 0;
+    let mut best_items = None;
+    for weapon in shop.weapons.iter() {
+        for armor in shop.armor.iter() {
+            for (i, ring1) in shop.rings.iter().enumerate() {
+                for ring2 in shop.rings.iter().skip(i + 1) {
+                    let (cost, stats) = equip([weapon, armor, ring1, ring2]);
+                    let equipped_player = Entity{hp: player.hp, stats};
+                    if cost > best && !defeats(&equipped_player, boss) {
+                        best = cost;
+                        best_items = Some([weapon, armor, ring1, ring2]);
+                    }
+                }
+            }
+        }
+    }
+    let items = best_items?;
+    trace("loadout", || loadout(items));
+    Some(best)
+}
+
+pub struct Day21;
+
+impl Solution for Day21 {
+    const DAY: u8 = 21;
+    const TITLE: &'static str = "RPG Simulator 20XX";
+    type Input = Entity;
+
+    fn parse(input: &str) -> Result<Entity, ParseError> {
+        Entity::parse(input)
+    }
+
+    fn part1(boss: &Entity) -> Option<Answer> {
+        cheapest_win(boss).map(Answer::from)
+    }
+
+    fn part2(boss: &Entity) -> Option<Answer> {
+        most_expensive_loss(boss).map(Answer::from)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cheapest_win() {
+        let boss = Entity::new(104, 8, 1);
+        let (cost, events) = aoc_runner::collect(true, || cheapest_win(&boss));
+        assert_eq!(Some(78), cost);
+        assert_eq!("loadout: Longsword, Leather, Damage +1 for 78 gold, 8 damage and 1 armor", events[0].to_string());
+        assert_eq!(Some(148), most_expensive_loss(&boss));
+    }
+
+    #[test]
+    fn test_rings_bought_once() {
+        // buying the same ring twice would be cheaper, but the shop has only one of each
+        let boss = Entity::new(95, 10, 3);
+        assert_eq!(Some(153), cheapest_win(&boss));
+    }
+
+    #[test]
+    fn test_no_outcome() {
+        assert_eq!(None, cheapest_win(&Entity::new(1000, 100, 100)));
+        assert_eq!(None, most_expensive_loss(&Entity::new(1, 0, 0)));
+    }
+}
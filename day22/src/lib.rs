@@ -0,0 +1,282 @@
+#![allow(unused)]
+#![allow(dead_code)]
+
+use std::cmp::{max, min};
+
+use aoc_runner::{lines, trace, Answer, Line, ParseError, Solution};
+use aoc_search::{search, SearchState, Strategy};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Boss {
+    hp: isize,
+    damage: isize
+}
+
+impl Boss {
+    fn attack(&self, player: &mut Player) {
+        player.hp -= max(1, self.damage - player.get_armor());
+    }
+}
+
+impl Boss {
+    pub fn new(hp: isize, damage: isize) -> Boss {
+        Boss{hp, damage}
+    }
+
+    /// Parse the hit points and damage of the boss, one per line.
+    pub fn parse(input: &str) -> Result<Boss, ParseError> {
+        let lines: Vec<Line> = lines(input).collect();
+        let parsenum = |index: usize, expected| -> Result<isize, ParseError> {
+            let line = lines.get(index).ok_or_else(|| ParseError::new(index + 1, 1, expected, ""))?;
+            line.words().find_map(|w| w.parse::<isize>().ok()).ok_or_else(|| line.error_at_end(expected))
+        };
+        Ok(Boss{hp: parsenum(0, "hit points")?, damage: parsenum(1, "damage")?})
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Player {
+    hp: isize,
+    mana: isize,
+    poisonduration: usize,
+    armorduration: usize,
+    manaduration: usize
+}
+
+impl Player {
+    pub fn new(hp: isize, mana: isize) -> Player {
+        Player{hp, mana, poisonduration: 0, armorduration: 0, manaduration: 0}
+    }
+    fn attack(&self, boss: &mut Boss) {
+        boss.hp -= self.get_damage();
+    }
+    fn update_effects(&mut self) {
+        if self.poisonduration > 0 {
+            self.poisonduration -= 1;
+        }
+        if self.armorduration > 0 {
+            self.armorduration -= 1;
+        }
+        if self.manaduration > 0 {
+            self.manaduration -= 1;
+        }
+    }
+
+    fn get_damage(&self) -> isize {
+        if self.poisonduration > 0 { 3 } else { 0 }
+    }
+
+    fn get_armor(&self) -> isize {
+        if self.armorduration > 0 { 7 } else { 0 }
+    }
+
+    fn get_manaregen(&self) -> isize {
+        if self.manaduration > 0 { 101 } else { 0 }  
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum Spell {
+    MagicMissile,
+    Drain,
+    Shield,
+    Poison,
+    Recharge
+}
+
+impl Spell {
+    fn cost(&self) -> isize {
+        match self {
+            Spell::MagicMissile => 53,
+            Spell::Drain => 73,
+            Spell::Shield => 113,
+            Spell::Poison => 173,
+            Spell::Recharge => 229
+        }
+    }
+
+    fn name(&self) -> &'static str {
+        match self {
+            Spell::MagicMissile => "Magic Missile",
+            Spell::Drain => "Drain",
+            Spell::Shield => "Shield",
+            Spell::Poison => "Poison",
+            Spell::Recharge => "Recharge"
+        }
+    }
+
+    fn cast(&self, player: &mut Player, boss: &mut Boss) {
+        assert!(player.mana >= self.cost());
+        match self {
+            Spell::MagicMissile => {
+                boss.hp -= 4;
+            },
+            Spell::Drain => {
+                boss.hp -= 2;
+                player.hp += 2;
+            },
+            Spell::Shield => {
+                assert!(player.armorduration == 0);
+                player.armorduration = 6;
+            },
+            Spell::Poison => {
+                assert!(player.poisonduration == 0);
+                player.poisonduration = 6;
+            },
+            Spell::Recharge => {
+                assert!(player.manaduration == 0);
+                player.manaduration = 5;
+            }
+        };
+        player.mana -= self.cost();
+        player.mana += player.get_manaregen();
+        player.attack(boss);
+        player.update_effects();
+        if boss.hp > 0 {
+            boss.attack(player);
+        }
+    }
+}
+
+/// On hard difficulty the player loses a hit point at the start of each of
+/// their turns.
+#[derive(Debug, Eq, PartialEq)]
+pub enum Difficulty {
+    Normal,
+    Hard
+}
+
+#[derive(Debug, Clone)]
+struct Battle {
+    player: Player,
+    boss: Boss,
+    hard: bool,
+    // the spell cast in the round leading here
+    spell: Option<Spell>,
+}
+
+impl SearchState for Battle {
+    type Key = (Player, Boss);
+    // mana spent
+    type Cost = isize;
+
+    // one round: the effects, the spell of the player and the attack of the boss
+    fn successors(&self) -> Vec<(Battle, isize)> {
+        let mut nplayer = self.player;
+        if self.hard {
+            nplayer.hp -= 1;
+        }
+        if nplayer.hp <= 0 {
+            return Vec::new();
+        }
+        let mut nboss = self.boss;
+        nplayer.mana += self.player.get_manaregen();
+        nplayer.attack(&mut nboss);
+        nplayer.update_effects();
+        if nboss.hp <= 0 {
+            return vec![(Battle{player: nplayer, boss: nboss, hard: self.hard, spell: None}, 0)];
+        }
+        let mut next = Vec::new();
+        for spell in [Spell::MagicMissile, Spell::Drain, Spell::Poison, Spell::Shield, Spell::Recharge].iter() {
+            // recharge has already paid out for this turn
+            if spell.cost() > nplayer.mana {
+                continue;
+            }
+            if *spell == Spell::Poison && nplayer.poisonduration > 0
+                || *spell == Spell::Shield && nplayer.armorduration > 0
+                || *spell == Spell::Recharge && nplayer.manaduration > 0 {
+                continue;
+            }
+            let mut nextboss = nboss;
+            let mut nextplayer = nplayer;
+            spell.cast(&mut nextplayer, &mut nextboss);
+            next.push((Battle{player: nextplayer, boss: nextboss, hard: self.hard, spell: Some(*spell)}, spell.cost()));
+        }
+        next
+    }
+
+    fn is_goal(&self) -> bool {
+        self.boss.hp <= 0
+    }
+
+    fn key(&self) -> (Player, Boss) {
+        (self.player, self.boss)
+    }
+}
+
+/// Least amount of mana `player` can spend and still win against `boss`,
+/// `None` if they cannot win.
+pub fn find_best_strategy(player: &Player, boss: &Boss, difficulty: Difficulty) -> Option<isize> {
+    let battle = Battle{player: *player, boss: *boss, hard: difficulty == Difficulty::Hard, spell: None};
+    let found = search(battle, Strategy::Dijkstra).found?;
+    trace("spells", || found.path.iter().filter_map(|battle| battle.spell).map(|spell| spell.name()).collect::<Vec<_>>().join(", "));
+    trace("end", || format!("the boss dies, leaving the player with {} hit points and {} mana", found.goal().player.hp, found.goal().player.mana));
+    Some(found.cost)
+}
+
+pub struct Day22;
+
+impl Solution for Day22 {
+    const DAY: u8 = 22;
+    const TITLE: &'static str = "Wizard Simulator 20XX";
+    type Input = Boss;
+
+    fn parse(input: &str) -> Result<Boss, ParseError> {
+        Boss::parse(input)
+    }
+
+    fn part1(boss: &Boss) -> Option<Answer> {
+        let player = Player::new(50, 500);
+        find_best_strategy(&player, boss, Difficulty::Normal).map(Answer::from)
+    }
+
+    fn part2(boss: &Boss) -> Option<Answer> {
+        let player = Player::new(50, 500);
+        find_best_strategy(&player, boss, Difficulty::Hard).map(Answer::from)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_poison_missile() {
+        let player = Player::new(10, 250);
+        let boss = Boss{hp: 13, damage: 8};
+        assert_eq!(Some(173 + 53), find_best_strategy(&player, &boss, Difficulty::Normal));
+    }
+
+    #[test]
+    fn test_medium() {
+        let player = Player::new(10, 250);
+        let boss = Boss{hp: 14, damage: 8};
+        assert_eq!(Some(229 + 113 + 73 + 173 + 53), find_best_strategy(&player, &boss, Difficulty::Normal));
+    }
+
+    #[test]
+    fn test_hard_mode_death() {
+        // the lost hit point kills the player before they can cast anything
+        let player = Player::new(1, 250);
+        let boss = Boss::new(4, 8);
+        assert_eq!(None, find_best_strategy(&player, &boss, Difficulty::Hard));
+    }
+
+    #[test]
+    fn test_recharge_before_casting() {
+        // recharge pays out before the spell is chosen
+        let mut player = Player::new(10, 100);
+        player.manaduration = 1;
+        let battle = Battle{player, boss: Boss::new(20, 8), hard: false, spell: None};
+        let costs: Vec<isize> = battle.successors().into_iter().map(|(_, cost)| cost).collect();
+        assert!(costs.contains(&Spell::Poison.cost()));
+    }
+
+    #[test]
+    fn test_dead_boss_does_not_attack() {
+        let mut player = Player::new(10, 250);
+        let mut boss = Boss::new(4, 8);
+        Spell::MagicMissile.cast(&mut player, &mut boss);
+        assert_eq!(10, player.hp);
+    }
+}
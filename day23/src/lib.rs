@@ -0,0 +1,139 @@
+#![allow(unused)]
+#![allow(dead_code)]
+
+use std::str::SplitWhitespace;
+
+use aoc_runner::{lines, Answer, Line, ParseError, Solution};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Instruction {
+    Hlf(usize),
+    Tpl(usize),
+    Inc(usize),
+    Jmp(isize),
+    Jie(usize, isize),
+    Jio(usize, isize),
+}
+
+fn next_register<'a>(line: Line<'a>, words: &mut SplitWhitespace<'a>) -> Result<usize, ParseError> {
+    let token = line.next(words, "a register")?;
+    match token.trim_end_matches(',') {
+        "a" => Ok(0),
+        "b" => Ok(1),
+        _ => Err(line.error(token, "register 'a' or 'b'"))
+    }
+}
+
+fn next_offset<'a>(line: Line<'a>, words: &mut SplitWhitespace<'a>) -> Result<isize, ParseError> {
+    line.parse(line.next(words, "an offset")?, "an offset")
+}
+
+impl Instruction {
+    /// Parse an instruction like "jio a, +19".
+    pub fn parse(line: Line) -> Result<Instruction, ParseError> {
+        let mut words = line.words();
+        let instruction = match line.next(&mut words, "an instruction")? {
+            "hlf" => Instruction::Hlf(next_register(line, &mut words)?),
+            "tpl" => Instruction::Tpl(next_register(line, &mut words)?),
+            "inc" => Instruction::Inc(next_register(line, &mut words)?),
+            "jmp" => Instruction::Jmp(next_offset(line, &mut words)?),
+            "jie" => Instruction::Jie(next_register(line, &mut words)?, next_offset(line, &mut words)?),
+            "jio" => Instruction::Jio(next_register(line, &mut words)?, next_offset(line, &mut words)?),
+            opcode => return Err(line.error(opcode, "one of 'hlf', 'tpl', 'inc', 'jmp', 'jie' or 'jio'"))
+        };
+        line.end(&mut words)?;
+        Ok(instruction)
+    }
+}
+
+/// Run `instructions` with registers a and b set to `startregs` until the
+/// machine halts, returning the value of register b.
+pub fn simulate_machine(instructions: &[Instruction], startregs: &[usize; 2]) -> i64 {
+    let mut rip: usize = 0;
+    let mut regs = *startregs;
+    let end = instructions.len();
+    while rip < end {
+        let offset = match instructions[rip] {
+            Instruction::Hlf(register) => {
+                regs[register] /= 2;
+                1
+            },
+            Instruction::Tpl(register) => {
+                regs[register] *= 3;
+                1
+            },
+            Instruction::Inc(register) => {
+                regs[register] += 1;
+                1
+            },
+            Instruction::Jmp(offset) => offset,
+            Instruction::Jie(register, offset) => if regs[register].is_multiple_of(2) { offset } else { 1 },
+            Instruction::Jio(register, offset) => if regs[register] == 1 { offset } else { 1 },
+        };
+        // jumping before the first instruction halts the machine as well
+        match rip.checked_add_signed(offset) {
+            Some(next) => rip = next,
+            None => break
+        }
+    }
+    regs[1] as i64
+}
+
+pub struct Day23;
+
+impl Solution for Day23 {
+    const DAY: u8 = 23;
+    const TITLE: &'static str = "Opening the Turing Lock";
+    type Input = Vec<Instruction>;
+
+    fn parse(input: &str) -> Result<Vec<Instruction>, ParseError> {
+        lines(input).map(Instruction::parse).collect()
+    }
+
+    fn part1(instructions: &Vec<Instruction>) -> Option<Answer> {
+        Some(simulate_machine(instructions, &[0; 2]).into())
+    }
+
+    fn part2(instructions: &Vec<Instruction>) -> Option<Answer> {
+        Some(simulate_machine(instructions, &[1, 0]).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    static TESTINPUT: &str = "jio a, +3\ninc b\ntpl b\ninc b";
+
+    #[test]
+    fn test_example() {
+        // example from the puzzle, operating on register b instead of a
+        let program = Day23::parse("inc b\njio b, +2\ntpl b\ninc b").unwrap();
+        assert_eq!(2, simulate_machine(&program, &[0; 2]));
+    }
+
+    #[test]
+    fn test_jump_backwards() {
+        // jio and jie back to the start
+        let program = Day23::parse("inc b\njio b, -1\njie b, -2\ntpl b").unwrap();
+        assert_eq!(9, simulate_machine(&program, &[0; 2]));
+    }
+
+    #[test]
+    fn test_part1() {
+        let program = Day23::parse(TESTINPUT).unwrap();
+        assert_eq!(Some(4.into()), Day23::part1(&program));
+    }
+
+    #[test]
+    fn test_part2() {
+        let program = Day23::parse(TESTINPUT).unwrap();
+        assert_eq!(Some(1.into()), Day23::part2(&program));
+    }
+
+    #[test]
+    fn test_parse_error() {
+        assert_eq!(Err(ParseError::new(2, 1, "one of 'hlf', 'tpl', 'inc', 'jmp', 'jie' or 'jio'", "dec")), Day23::parse("inc a\ndec b"));
+        assert_eq!(Err(ParseError::new(1, 5, "register 'a' or 'b'", "c,")), Day23::parse("jio c, +2"));
+        assert_eq!(Err(ParseError::new(1, 5, "an offset", "two")), Day23::parse("jmp two"));
+    }
+}
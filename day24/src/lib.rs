@@ -0,0 +1,211 @@
+#![allow(unused)]
+#![allow(dead_code)]
+
+use std::cmp::{min, max};
+
+use aoc_runner::{lines, trace, Answer, ParseError, Solution};
+use aoc_search::{search, SearchState, Strategy};
+
+/// Parse one present weight per line.
+///
+/// Whether the presents can be split evenly is up to [`balance`], only their
+/// total weight has to fit into a `usize`.
+pub fn parse_presents(input: &str) -> Result<Vec<usize>, ParseError> {
+    let presents: Vec<usize> = lines(input).map(|line| match line.parse::<usize>(line.text, "a weight")? {
+        0 => Err(line.error(line.text, "a positive weight")),
+        weight => Ok(weight),
+    }).collect::<Result<_, _>>()?;
+    let Some(last) = lines(input).last() else {
+        return Err(ParseError::new(1, 1, "a weight", ""));
+    };
+    if presents.iter().try_fold(0usize, |total, weight| total.checked_add(*weight)).is_none() {
+        return Err(last.error_at_end("a smaller total weight"));
+    }
+    Ok(presents)
+}
+
+fn quantum_entanglement(presents: &[usize]) -> usize {
+    presents.iter().product()
+}
+
+fn has_valid_combination(presents: &[usize], assigned: &mut [bool], weights: &[usize], target_weight: usize) -> bool {
+    assert!(assigned.len() == presents.len());
+    if weights.is_empty() || weights.len() == 1 && weights[0] == target_weight {
+        return true;
+    }
+    assert!(weights[0] < target_weight);
+    let missing = target_weight - weights[0];
+    let unassigned: Vec<usize> = (0..presents.len()).filter(|n| !assigned[*n] && presents[*n] <= missing).collect();
+    for n in unassigned {
+        assigned[n] = true;
+        let present = presents[n];
+        let mut nweights = if present == missing { weights[1..].to_vec() } else { weights.to_vec() };
+        if present != missing {
+            nweights[0] += present;
+        }
+        assert!(nweights.is_empty() || nweights[0] < target_weight);
+        if has_valid_combination(presents, assigned, &nweights, target_weight) {
+            return true;
+        }
+        assigned[n] = false;
+    }
+
+    false
+}
+
+// size of the first group, then its quantum entanglement
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+struct Entanglement {
+    presents: usize,
+    product: usize,
+}
+
+impl Default for Entanglement {
+    fn default() -> Self {
+        Entanglement{presents: 0, product: 1}
+    }
+}
+
+impl Entanglement {
+    // `None` if the product overflows
+    fn checked_add(self, other: Entanglement) -> Option<Entanglement> {
+        Some(Entanglement{presents: self.presents + other.presents, product: self.product.checked_mul(other.product)?})
+    }
+}
+
+impl std::ops::Add for Entanglement {
+    type Output = Entanglement;
+
+    // the search only adds steps that `successors` checked
+    fn add(self, other: Entanglement) -> Entanglement {
+        self.checked_add(other).expect("entanglement overflow")
+    }
+}
+
+// the first group, built by adding presents in the order of the input
+#[derive(Debug, Clone)]
+struct FirstGroup<'a> {
+    presents: &'a [usize],
+    num_groups: usize,
+    partition_weight: usize,
+    chosen: Vec<usize>,
+    weight: usize,
+    entanglement: Entanglement,
+}
+
+impl SearchState for FirstGroup<'_> {
+    type Key = Vec<usize>;
+    type Cost = Entanglement;
+
+    fn successors(&self) -> Vec<(Self, Entanglement)> {
+        let next = self.chosen.last().map_or(0, |last| last + 1);
+        (next..self.presents.len())
+            .filter(|n| self.weight + self.presents[*n] <= self.partition_weight)
+            .filter_map(|n| {
+                // groups whose entanglement overflows cannot be the smallest
+                let step = Entanglement{presents: 1, product: self.presents[n]};
+                let entanglement = self.entanglement.checked_add(step)?;
+                let mut chosen = self.chosen.clone();
+                chosen.push(n);
+                let group = FirstGroup{chosen, weight: self.weight + self.presents[n], entanglement, ..*self};
+                Some((group, step))
+            })
+            .collect()
+    }
+
+    // the remaining presents can be split into the other groups
+    fn is_goal(&self) -> bool {
+        if self.weight != self.partition_weight {
+            return false;
+        }
+        let remaining_presents: Vec<usize> = (0..self.presents.len()).filter(|n| !self.chosen.contains(n)).map(|n| self.presents[n]).collect();
+        let mut assigned = vec![false; remaining_presents.len()];
+        let weights = vec![0; self.num_groups - 1];
+        has_valid_combination(&remaining_presents, &mut assigned, &weights, self.partition_weight)
+    }
+
+    fn key(&self) -> Vec<usize> {
+        self.chosen.clone()
+    }
+}
+
+/// Quantum entanglement of the smallest first group when splitting the
+/// `presents` into `num_groups` groups of equal weight, `None` if they
+/// cannot be split like that.
+pub fn balance(presents: &[usize], num_groups: usize) -> Option<usize> {
+    let total = presents.iter().sum::<usize>();
+    if total == 0 || num_groups == 0 || !total.is_multiple_of(num_groups) {
+        return None;
+    }
+    let partition_weight = total / num_groups;
+
+    let start = FirstGroup{presents, num_groups, partition_weight, chosen: Vec::new(), weight: 0, entanglement: Entanglement::default()};
+    let outcome = search(start, Strategy::Dijkstra);
+    let found = outcome.found?;
+    trace("first group", || {
+        let group: Vec<String> = found.goal().chosen.iter().map(|n| presents[*n].to_string()).collect();
+        format!("{} = {}, {} presents with entanglement {}", group.join(" + "), partition_weight, found.cost.presents, found.cost.product)
+    });
+    trace("search", || format!("{} groups expanded", outcome.stats.expanded));
+    Some(found.cost.product)
+}
+
+pub struct Day24;
+
+impl Solution for Day24 {
+    const DAY: u8 = 24;
+    const TITLE: &'static str = "It Hangs in the Balance";
+    type Input = Vec<usize>;
+
+    fn parse(input: &str) -> Result<Vec<usize>, ParseError> {
+        parse_presents(input)
+    }
+
+    fn part1(presents: &Vec<usize>) -> Option<Answer> {
+        balance(presents, 3).map(Answer::from)
+    }
+
+    fn part2(presents: &Vec<usize>) -> Option<Answer> {
+        balance(presents, 4).map(Answer::from)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_error() {
+        assert_eq!(Err(ParseError::new(1, 1, "a weight", "")), Day24::parse(""));
+        assert_eq!(Err(ParseError::new(2, 21, "a smaller total weight", "")), Day24::parse(&format!("{}\n{}", usize::MAX, usize::MAX)));
+        assert_eq!(Err(ParseError::new(2, 1, "a positive weight", "0")), Day24::parse("12\n0"));
+        assert_eq!(Ok(vec![5, 7]), Day24::parse("5\n7"));
+        // a total of 15 splits into three groups, but not into four
+        let presents = Day24::parse("1\n2\n3\n4\n5").unwrap();
+        assert_eq!(Some(5.into()), Day24::part1(&presents));
+        assert_eq!(None, Day24::part2(&presents));
+    }
+
+    #[test]
+    fn test_balance() {
+        let presents = [1, 2, 3, 4, 5, 7, 8, 9, 10, 11];
+        assert_eq!(Some(99), balance(&presents, 3));
+        assert_eq!(Some(44), balance(&presents, 4));
+    }
+
+    #[test]
+    fn test_no_split() {
+        assert_eq!(None, balance(&[1, 2], 3));
+        assert_eq!(None, balance(&[5, 7], 3));
+        assert_eq!(None, Day24::part2(&Day24::parse("5\n7").unwrap()));
+        assert_eq!(None, balance(&[], 3));
+    }
+
+    #[test]
+    fn test_overflow() {
+        // the only pair weighing half has an entanglement beyond u64, so the
+        // smallest group is the triple
+        let presents = [(1 << 32) + 1, (1 << 32) + 1, 1 << 33, 1, 1];
+        assert_eq!(Some(1 << 33), balance(&presents, 2));
+    }
+}
@@ -0,0 +1,95 @@
+#![allow(unused)]
+#![allow(dead_code)]
+
+use aoc_math::{mod_mul, mod_pow};
+use aoc_runner::{lines, Answer, Line, ParseError, Solution};
+
+const STARTCODE: u64 = 20151125;
+const FACTOR: u64 = 252533;
+const MODULUS: u64 = 33554393;
+/// Largest row or column [`code_at`] can find the index of without overflowing.
+pub const LIMIT: usize = 1_000_000_000;
+
+/// Code at `row` and `column` of the manual, both starting at 1.
+pub fn code_at(row: usize, column: usize) -> usize {
+    // project diagonally onto y axis
+    let standardrow = row + column - 1;
+    let index = (standardrow * (standardrow - 1)) / 2 + column;
+    // every code is the previous one times FACTOR
+    mod_mul(STARTCODE, mod_pow(FACTOR, index as u64 - 1, MODULUS), MODULUS) as usize
+}
+
+pub struct Day25;
+
+impl Solution for Day25 {
+    const DAY: u8 = 25;
+    const TITLE: &'static str = "Let It Snow";
+    // row and column of the requested code
+    type Input = (usize, usize);
+
+    // "... Enter the code at row 2947, column 3029."
+    fn parse(input: &str) -> Result<(usize, usize), ParseError> {
+        let line = lines(input).next().unwrap_or(Line { number: 1, text: "" });
+        let mut words = line.words();
+        let mut number_after = |label: &str| -> Result<usize, ParseError> {
+            if !words.by_ref().any(|word| word == label) {
+                return Err(line.error_at_end(&format!("'{}'", label)));
+            }
+            let expected = format!("a {} number between 1 and {}", label, LIMIT);
+            let token = line.next(&mut words, &expected)?.trim_end_matches([',', '.']);
+            match line.parse(token, &expected)? {
+                number if (1..=LIMIT).contains(&number) => Ok(number),
+                _ => Err(line.error(token, &expected)),
+            }
+        };
+        Ok((number_after("row")?, number_after("column")?))
+    }
+
+    fn part1(&(row, column): &(usize, usize)) -> Option<Answer> {
+        Some(code_at(row, column).into())
+    }
+
+    fn part2(_: &(usize, usize)) -> Option<Answer> {
+        // there is no second puzzle on the last day
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    static TESTINPUT: &str = "To continue, please consult the code grid in the manual.  Enter the code at row 1, column 3.";
+    static TESTINPUT2: &str = "To continue, please consult the code grid in the manual.  Enter the code at row 2, column 1.";
+
+    #[test]
+    fn test_part1_13() {
+        let position = Day25::parse(TESTINPUT).unwrap();
+        assert_eq!(Some(17289845.into()), Day25::part1(&position));
+    }
+
+    #[test]
+    fn test_part1_21() {
+        let position = Day25::parse(TESTINPUT2).unwrap();
+        assert_eq!(Some(31916031.into()), Day25::part1(&position));
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let expected = "a column number between 1 and 1000000000";
+        assert_eq!(Err(ParseError::new(1, 33, expected, "0")), Day25::parse("Enter the code at row 1, column 0."));
+        assert_eq!(Err(ParseError::new(1, 33, expected, "1000000001")), Day25::parse("Enter the code at row 1, column 1000000001."));
+        assert_eq!(Err(ParseError::new(1, 23, "a row number between 1 and 1000000000", "x")), Day25::parse("Enter the code at row x, column 1."));
+    }
+
+    #[test]
+    fn test_code_at() {
+        // the corner of the table in the puzzle
+        assert_eq!(27995004, code_at(6, 6));
+        assert_eq!(33511524, code_at(1, 6));
+        let mut current = STARTCODE;
+        for _ in 1..(100 * 99 / 2 + 1) {
+            current = current * FACTOR % MODULUS;
+        }
+        assert_eq!(current as usize, code_at(100, 1));
+    }
+}